@@ -0,0 +1,123 @@
+//! RNG wrapper enforcing a budget of output bytes.
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::Error;
+
+/// RNG drawing at most a fixed number of bytes from the wrapped RNG
+///
+/// Requests exceeding the remaining budget are rejected before any bytes are
+/// drawn: [RngCore::try_fill_bytes] fails with [Error::BudgetExceeded] and the
+/// infallible methods panic. This allows harnesses to detect schemes consuming
+/// more randomness than their specification allows:
+/// ```should_panic
+/// use nist_pqc_seeded_rng::{
+///     budget::BudgetRng, NistPqcAes256CtrRng, RngCore, Seed, SeedableRng,
+/// };
+///
+/// let mut rng = BudgetRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()), 64);
+/// let mut coins = [0; 32];
+/// rng.fill_bytes(&mut coins);
+/// rng.fill_bytes(&mut coins);
+/// // panics
+/// rng.next_u32();
+/// ```
+///
+/// All requests are forwarded unchanged, so the output is the output of the
+/// wrapped RNG.
+#[derive(Clone, Debug)]
+pub struct BudgetRng<R: RngCore> {
+    rng: R,
+    remaining: usize,
+    used: usize,
+}
+
+impl<R: RngCore> BudgetRng<R> {
+    /// Allow at most `budget` bytes to be drawn from `rng`.
+    pub const fn new(rng: R, budget: usize) -> Self {
+        Self {
+            rng,
+            remaining: budget,
+            used: 0,
+        }
+    }
+
+    /// Number of bytes drawn so far.
+    pub const fn used(&self) -> usize {
+        self.used
+    }
+
+    /// Number of bytes that can still be drawn.
+    pub const fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Return the wrapped RNG.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+
+    /// Charge `length` bytes to the budget.
+    fn charge(&mut self, length: usize) -> Result<(), Error> {
+        if length > self.remaining {
+            return Err(Error::BudgetExceeded);
+        }
+        self.remaining -= length;
+        self.used += length;
+        Ok(())
+    }
+}
+
+impl<R: RngCore> RngCore for BudgetRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.charge(4).expect("randomness budget exceeded");
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.charge(8).expect("randomness budget exceeded");
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.charge(dest.len()).expect("randomness budget exceeded");
+        self.rng.fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.charge(dest.len())?;
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+impl<R: RngCore + CryptoRng> CryptoRng for BudgetRng<R> {}
+
+#[cfg(test)]
+mod test {
+    use rand_core::SeedableRng;
+
+    use super::*;
+    use crate::{NistPqcAes256CtrRng, Seed};
+
+    #[test]
+    fn within_budget() {
+        let mut rng = BudgetRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()), 14);
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::default());
+        assert_eq!(rng.next_u64(), reference.next_u64());
+        assert!(rng.try_fill_bytes(&mut [0; 7]).is_err());
+        assert_eq!(rng.used(), 8);
+        assert_eq!(rng.remaining(), 6);
+        assert_eq!(rng.next_u32(), reference.next_u32());
+        rng.fill_bytes(&mut [0; 2]);
+        assert_eq!(rng.remaining(), 0);
+        assert!(rng.try_fill_bytes(&mut []).is_ok());
+        assert_eq!(rng.into_inner().reseed_counter(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "randomness budget exceeded")]
+    fn exceeded() {
+        let mut rng = BudgetRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()), 7);
+        rng.next_u64();
+    }
+}
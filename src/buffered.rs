@@ -0,0 +1,296 @@
+//! Buffered word and byte output for the seeded CTR_DRBG.
+
+use aes::Aes256;
+use rand_core::{block::BlockRngCore, CryptoRng, RngCore, SeedableRng};
+
+use crate::{CtrDrbgCipher, SeededCtrDrbg};
+
+/// Number of bytes requested from the DRBG to refill the buffer.
+pub const BUFFER_LENGTH: usize = 64;
+
+/// [SeededCtrDrbg] serving words from a buffer
+///
+/// With [SeededCtrDrbg], every call to [RngCore::next_u32] or
+/// [RngCore::next_u64] is a separate request including the update of the
+/// state. This RNG instead requests [BUFFER_LENGTH] bytes at once and returns
+/// the words as consecutive little-endian chunks of the buffer. A `u64` never
+/// spans two requests; if fewer than 8 bytes are left, they are discarded.
+/// [RngCore::fill_bytes] is forwarded to the DRBG without touching the buffer.
+///
+/// Warning: The mapping from the output of the DRBG to words differs from
+/// [SeededCtrDrbg]. Hence, schemes drawing words produce different results
+/// than with the NIST KATs.
+#[derive(Debug)]
+#[cfg_attr(feature = "zeroize", derive(zeroize::ZeroizeOnDrop))]
+pub struct WordBufferedRng<C: CtrDrbgCipher = Aes256> {
+    rng: SeededCtrDrbg<C>,
+    buffer: [u8; BUFFER_LENGTH],
+    position: usize,
+}
+
+impl<C: CtrDrbgCipher> WordBufferedRng<C> {
+    /// Serve words of `rng` from a buffer.
+    pub const fn new(rng: SeededCtrDrbg<C>) -> Self {
+        Self {
+            rng,
+            buffer: [0; BUFFER_LENGTH],
+            position: BUFFER_LENGTH,
+        }
+    }
+
+    /// Take `N` bytes from the buffer and refill it if necessary.
+    fn take<const N: usize>(&mut self) -> [u8; N] {
+        if self.position + N > BUFFER_LENGTH {
+            self.rng.fill_bytes(&mut self.buffer);
+            self.position = 0;
+        }
+        let mut word = [0; N];
+        word.copy_from_slice(&self.buffer[self.position..self.position + N]);
+        self.position += N;
+        word
+    }
+}
+
+impl<C: CtrDrbgCipher> From<SeededCtrDrbg<C>> for WordBufferedRng<C> {
+    fn from(value: SeededCtrDrbg<C>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<C: CtrDrbgCipher> SeedableRng for WordBufferedRng<C> {
+    type Seed = C::Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(SeededCtrDrbg::from_seed(seed))
+    }
+}
+
+impl<C: CtrDrbgCipher> RngCore for WordBufferedRng<C> {
+    fn next_u32(&mut self) -> u32 {
+        u32::from_le_bytes(self.take())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        u64::from_le_bytes(self.take())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+impl<C: CtrDrbgCipher> CryptoRng for WordBufferedRng<C> {}
+
+/// Every block is the output of a single request of [BUFFER_LENGTH] bytes
+/// decoded as little-endian words. Hence, [BlockRng](rand_core::block::BlockRng)
+/// serves words like [WordBufferedRng], but also serves
+/// [RngCore::fill_bytes] from the buffer:
+/// ```
+/// use nist_pqc_seeded_rng::{NistPqcAes256CtrRng, RngCore, Seed, SeedableRng};
+/// use rand_core::block::BlockRng;
+///
+/// let mut rng = BlockRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()));
+/// let value = rng.next_u32();
+/// ```
+///
+/// Warning: The outputs of [BlockRng](rand_core::block::BlockRng) differ from
+/// [SeededCtrDrbg]. Hence, schemes produce different results than with the
+/// NIST KATs.
+impl<C: CtrDrbgCipher> BlockRngCore for SeededCtrDrbg<C> {
+    type Item = u32;
+    type Results = [u32; BUFFER_LENGTH / 4];
+
+    fn generate(&mut self, results: &mut Self::Results) {
+        self.fill_u32_slice(results);
+    }
+}
+
+/// Iterator over the output bytes of a [SeededCtrDrbg]
+///
+/// The iterator requests [BUFFER_LENGTH] bytes at once. Hence, the bytes are
+/// the concatenation of the outputs of requests of [BUFFER_LENGTH] bytes.
+/// Unconsumed bytes of the last request are discarded when the iterator is
+/// dropped.
+///
+/// The iterator is created by [SeededCtrDrbg::iter_bytes].
+#[derive(Debug)]
+#[cfg_attr(feature = "zeroize", derive(zeroize::ZeroizeOnDrop))]
+pub struct IterBytes<'a, C: CtrDrbgCipher> {
+    #[cfg_attr(feature = "zeroize", zeroize(skip))]
+    rng: &'a mut SeededCtrDrbg<C>,
+    buffer: [u8; BUFFER_LENGTH],
+    position: usize,
+}
+
+impl<C: CtrDrbgCipher> Iterator for IterBytes<'_, C> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position == BUFFER_LENGTH {
+            self.rng.fill_bytes(&mut self.buffer);
+            self.position = 0;
+        }
+        let byte = self.buffer[self.position];
+        self.position += 1;
+        Some(byte)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<C: CtrDrbgCipher> core::iter::FusedIterator for IterBytes<'_, C> {}
+
+/// Iterator over fixed-size arrays of output bytes of a [SeededCtrDrbg]
+///
+/// Every array is drawn with a separate request of `N` bytes, i.e., the
+/// iterator behaves like repeated calls to [SeededCtrDrbg::gen_array].
+///
+/// The iterator is created by [SeededCtrDrbg::chunks].
+#[derive(Debug)]
+pub struct Chunks<'a, C: CtrDrbgCipher, const N: usize> {
+    rng: &'a mut SeededCtrDrbg<C>,
+}
+
+impl<C: CtrDrbgCipher, const N: usize> Iterator for Chunks<'_, C, N> {
+    type Item = [u8; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.rng.gen_array())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<C: CtrDrbgCipher, const N: usize> core::iter::FusedIterator for Chunks<'_, C, N> {}
+
+impl<C: CtrDrbgCipher> SeededCtrDrbg<C> {
+    /// Iterate over output bytes requested in batches of [BUFFER_LENGTH]
+    /// bytes.
+    ///
+    /// ```
+    /// use nist_pqc_seeded_rng::{NistPqcAes256CtrRng, Seed, SeedableRng};
+    ///
+    /// let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+    /// // rejection sampling of a value in [0, 200)
+    /// let value = rng.iter_bytes().find(|byte| *byte < 200).unwrap();
+    /// ```
+    pub fn iter_bytes(&mut self) -> IterBytes<'_, C> {
+        IterBytes {
+            rng: self,
+            buffer: [0; BUFFER_LENGTH],
+            position: BUFFER_LENGTH,
+        }
+    }
+
+    /// Iterate over arrays of `N` output bytes, each drawn with a separate
+    /// request.
+    ///
+    /// ```
+    /// use nist_pqc_seeded_rng::{NistPqcAes256CtrRng, Seed, SeedableRng};
+    ///
+    /// let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+    /// let nonces: Vec<[u8; 32]> = rng.chunks().take(3).collect();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if the RNG is strict and rejects a request.
+    pub fn chunks<const N: usize>(&mut self) -> Chunks<'_, C, N> {
+        Chunks { rng: self }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{NistPqcAes256CtrRng, Seed};
+
+    #[test]
+    fn words() {
+        let mut rng = WordBufferedRng::<Aes256>::from_seed(Seed::default());
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut buf = [0; BUFFER_LENGTH];
+        reference.fill_bytes(&mut buf);
+
+        for chunk in buf[..BUFFER_LENGTH - 12].chunks_exact(4) {
+            assert_eq!(
+                rng.next_u32(),
+                u32::from_le_bytes(chunk.try_into().unwrap())
+            );
+        }
+        assert_eq!(
+            rng.next_u64(),
+            u64::from_le_bytes(
+                buf[BUFFER_LENGTH - 12..BUFFER_LENGTH - 4]
+                    .try_into()
+                    .unwrap()
+            )
+        );
+
+        // the remaining 4 bytes are discarded
+        reference.fill_bytes(&mut buf);
+        assert_eq!(
+            rng.next_u64(),
+            u64::from_le_bytes(buf[..8].try_into().unwrap())
+        );
+    }
+
+    #[test]
+    fn block_rng() {
+        let mut rng =
+            rand_core::block::BlockRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()));
+        let mut reference = WordBufferedRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()));
+        for _ in 0..20 {
+            assert_eq!(rng.next_u32(), reference.next_u32());
+        }
+        assert_eq!(rng.core.reseed_counter(), 3);
+    }
+
+    #[test]
+    fn iter_bytes() {
+        let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut buf = [0; 2 * BUFFER_LENGTH];
+        reference.fill_bytes(&mut buf[..BUFFER_LENGTH]);
+        reference.fill_bytes(&mut buf[BUFFER_LENGTH..]);
+
+        assert!(rng.iter_bytes().take(100).eq(buf[..100].iter().copied()));
+        assert_eq!(rng.reseed_counter(), 3);
+        assert_eq!(rng.next_u64(), reference.next_u64());
+    }
+
+    #[test]
+    fn chunks() {
+        let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::default());
+
+        let chunks: [[u8; 32]; 3] = {
+            let mut iter = rng.chunks();
+            core::array::from_fn(|_| iter.next().unwrap())
+        };
+        for chunk in chunks {
+            assert_eq!(chunk, reference.gen_array::<32>());
+        }
+        assert_eq!(rng.reseed_counter(), 4);
+        assert_eq!(rng.next_u64(), reference.next_u64());
+    }
+
+    #[test]
+    fn bytes() {
+        let mut rng = WordBufferedRng::from(NistPqcAes256CtrRng::from_seed(Seed::default()));
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut buf_1 = [0; 32];
+        let mut buf_2 = [0; 32];
+        rng.fill_bytes(&mut buf_1);
+        reference.fill_bytes(&mut buf_2);
+        assert_eq!(buf_1, buf_2);
+    }
+}
@@ -0,0 +1,178 @@
+//! RNG wrapper verifying the sequence of request lengths.
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::Error;
+
+/// RNG verifying that the requests follow an expected pattern of lengths
+///
+/// The state of the DRBG and hence the output depends on how the output is
+/// split into requests. This RNG catches harnesses whose call pattern differs
+/// from the C original before the outputs are compared:
+/// ```should_panic
+/// use nist_pqc_seeded_rng::{
+///     call_pattern::CallPatternRng, NistPqcAes256CtrRng, RngCore, Seed, SeedableRng,
+/// };
+///
+/// let mut rng = CallPatternRng::cycle(NistPqcAes256CtrRng::from_seed(Seed::default()), &[32, 16]);
+/// rng.fill_bytes(&mut [0; 32]);
+/// rng.fill_bytes(&mut [0; 16]);
+/// rng.fill_bytes(&mut [0; 32]);
+/// // panics: request of 8 bytes in call 3, expected 16 bytes
+/// rng.next_u64();
+/// ```
+///
+/// Requests deviating from the pattern are rejected before any bytes are
+/// drawn: [RngCore::try_fill_bytes] fails with [Error::UnexpectedRequest] and
+/// the infallible methods panic.
+#[derive(Clone, Debug)]
+pub struct CallPatternRng<'a, R: RngCore> {
+    rng: R,
+    pattern: &'a [usize],
+    cyclic: bool,
+    calls: usize,
+}
+
+impl<'a, R: RngCore> CallPatternRng<'a, R> {
+    /// Expect requests of the lengths in `pattern` and no further requests.
+    pub const fn new(rng: R, pattern: &'a [usize]) -> Self {
+        Self {
+            rng,
+            pattern,
+            cyclic: false,
+            calls: 0,
+        }
+    }
+
+    /// Expect requests of the lengths in `pattern` repeated indefinitely,
+    /// e.g., the requests of one KAT count.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is empty.
+    pub const fn cycle(rng: R, pattern: &'a [usize]) -> Self {
+        assert!(!pattern.is_empty(), "empty call pattern");
+        Self {
+            rng,
+            pattern,
+            cyclic: true,
+            calls: 0,
+        }
+    }
+
+    /// Number of requests so far.
+    pub const fn calls(&self) -> usize {
+        self.calls
+    }
+
+    /// Check whether all requests of the pattern have been made.
+    ///
+    /// For cyclic patterns, this checks whether the current repetition is
+    /// complete.
+    pub const fn is_complete(&self) -> bool {
+        if self.cyclic {
+            self.calls % self.pattern.len() == 0
+        } else {
+            self.calls == self.pattern.len()
+        }
+    }
+
+    /// Return the wrapped RNG.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+
+    /// Length of the next request expected by the pattern.
+    fn expected(&self) -> Option<usize> {
+        if self.cyclic {
+            Some(self.pattern[self.calls % self.pattern.len()])
+        } else {
+            self.pattern.get(self.calls).copied()
+        }
+    }
+
+    /// Check the length of the next request against the pattern.
+    fn check(&mut self, length: usize) -> Result<(), Error> {
+        if self.expected() != Some(length) {
+            return Err(Error::UnexpectedRequest);
+        }
+        self.calls += 1;
+        Ok(())
+    }
+
+    fn check_or_panic(&mut self, length: usize) {
+        if self.check(length).is_err() {
+            match self.expected() {
+                Some(expected) => panic!(
+                    "request of {} bytes in call {}, expected {} bytes",
+                    length, self.calls, expected
+                ),
+                None => panic!(
+                    "request of {} bytes in call {}, expected no further requests",
+                    length, self.calls
+                ),
+            }
+        }
+    }
+}
+
+impl<R: RngCore> RngCore for CallPatternRng<'_, R> {
+    fn next_u32(&mut self) -> u32 {
+        self.check_or_panic(4);
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.check_or_panic(8);
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.check_or_panic(dest.len());
+        self.rng.fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.check(dest.len())?;
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+impl<R: RngCore + CryptoRng> CryptoRng for CallPatternRng<'_, R> {}
+
+#[cfg(test)]
+mod test {
+    use rand_core::SeedableRng;
+
+    use super::*;
+    use crate::{NistPqcAes256CtrRng, Seed};
+
+    #[test]
+    fn pattern() {
+        let mut rng = CallPatternRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()), &[3, 4]);
+        assert!(rng.try_fill_bytes(&mut [0; 4]).is_err());
+        rng.fill_bytes(&mut [0; 3]);
+        assert!(!rng.is_complete());
+        rng.next_u32();
+        assert!(rng.is_complete());
+        assert!(rng.try_fill_bytes(&mut []).is_err());
+        assert_eq!(rng.calls(), 2);
+        assert_eq!(rng.into_inner().reseed_counter(), 3);
+    }
+
+    #[test]
+    fn cycle() {
+        let mut rng = CallPatternRng::cycle(NistPqcAes256CtrRng::from_seed(Seed::default()), &[8]);
+        for _ in 0..3 {
+            rng.next_u64();
+            assert!(rng.is_complete());
+        }
+        assert!(rng.try_fill_bytes(&mut [0; 7]).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "request of 4 bytes in call 0, expected no further requests")]
+    fn no_further_requests() {
+        CallPatternRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()), &[]).next_u32();
+    }
+}
@@ -0,0 +1,153 @@
+//! Deterministic RNG based on ChaCha20 with the same seed interface as the
+//! AES-CTR based RNGs.
+
+use core::fmt;
+
+use chacha20::{
+    cipher::{generic_array::GenericArray, KeyIvInit, StreamCipher, StreamCipherSeek},
+    ChaCha20,
+};
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+use crate::{Error, InvalidSeedLength, Seed, KEY_LENGTH};
+
+/// Length of a ChaCha20 block in bytes.
+const BLOCK_LENGTH: u64 = 64;
+
+/// RNG producing the ChaCha20 keystream from a 48 byte [Seed]
+///
+/// The first 32 bytes of the seed are used as key. The remaining 16 bytes
+/// consist of the initial 32 bit block counter in little-endian byte order
+/// followed by the 96 bit nonce as in RFC 8439. The output of the RNG is the
+/// keystream read sequentially.
+///
+/// This RNG can be used in place of [NistPqcAes256CtrRng](crate::NistPqcAes256CtrRng)
+/// to detect hidden dependencies on the AES-CTR based output. It does not
+/// produce outputs compatible with the known answer tests.
+pub struct ChaCha20SeededRng {
+    cipher: ChaCha20,
+}
+
+impl ChaCha20SeededRng {
+    /// Fill `dest` with the keystream.
+    ///
+    /// Fails with [Error::ReseedRequired] once the end of the keystream is
+    /// reached.
+    pub(crate) fn generate(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        dest.fill(0);
+        self.cipher
+            .try_apply_keystream(dest)
+            .map_err(|_| Error::ReseedRequired)
+    }
+}
+
+impl fmt::Debug for ChaCha20SeededRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChaCha20SeededRng").finish_non_exhaustive()
+    }
+}
+
+impl SeedableRng for ChaCha20SeededRng {
+    type Seed = Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        let counter = u32::from_le_bytes(seed[KEY_LENGTH..KEY_LENGTH + 4].try_into().unwrap());
+        let mut cipher = ChaCha20::new(
+            GenericArray::from_slice(&seed[..KEY_LENGTH]),
+            GenericArray::from_slice(&seed[KEY_LENGTH + 4..]),
+        );
+        cipher.seek(counter as u64 * BLOCK_LENGTH);
+        Self { cipher }
+    }
+}
+
+impl From<[u8; 48]> for ChaCha20SeededRng {
+    fn from(value: [u8; 48]) -> Self {
+        Self::from_seed(value.into())
+    }
+}
+
+impl From<&[u8; 48]> for ChaCha20SeededRng {
+    fn from(value: &[u8; 48]) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl TryFrom<&[u8]> for ChaCha20SeededRng {
+    type Error = InvalidSeedLength;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Seed::try_from(value).map(Self::from_seed)
+    }
+}
+
+impl RngCore for ChaCha20SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).expect("end of keystream reached")
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.generate(dest).map_err(Into::into)
+    }
+}
+
+impl CryptoRng for ChaCha20SeededRng {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn all_zeros() {
+        let mut rng = ChaCha20SeededRng::from_seed(Seed::default());
+        let mut buf = [0; 64];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(
+            buf,
+            [
+                0x76, 0xb8, 0xe0, 0xad, 0xa0, 0xf1, 0x3d, 0x90, 0x40, 0x5d, 0x6a, 0xe5, 0x53, 0x86,
+                0xbd, 0x28, 0xbd, 0xd2, 0x19, 0xb8, 0xa0, 0x8d, 0xed, 0x1a, 0xa8, 0x36, 0xef, 0xcc,
+                0x8b, 0x77, 0x0d, 0xc7, 0xda, 0x41, 0x59, 0x7c, 0x51, 0x57, 0x48, 0x8d, 0x77, 0x24,
+                0xe0, 0x3f, 0xb8, 0xd8, 0x4a, 0x37, 0x6a, 0x43, 0xb8, 0xf4, 0x15, 0x18, 0xa1, 0x1c,
+                0xc3, 0x87, 0xb6, 0x69, 0xb2, 0xee, 0x65, 0x86,
+            ]
+        );
+    }
+
+    #[test]
+    fn counter_and_nonce() {
+        let mut seed = [0; 48];
+        seed.iter_mut()
+            .enumerate()
+            .for_each(|(i, byte)| *byte = i as u8);
+        let mut rng = ChaCha20SeededRng::from(seed);
+        let mut buf = [0; 100];
+        rng.fill_bytes(&mut buf[..63]);
+        rng.fill_bytes(&mut buf[63..]);
+        assert_eq!(
+            buf,
+            [
+                0xc7, 0x93, 0x6a, 0x87, 0x09, 0xbb, 0x1e, 0x3c, 0x3c, 0x92, 0x3b, 0xf0, 0xa1, 0xd0,
+                0x65, 0xc5, 0x1d, 0x0e, 0xf9, 0x0d, 0x51, 0xa1, 0x3b, 0x9e, 0x47, 0xf0, 0xb6, 0x03,
+                0x27, 0xa2, 0xa1, 0x1a, 0x25, 0x1f, 0xa5, 0xf8, 0x19, 0x9b, 0x8f, 0x5c, 0x7a, 0x8c,
+                0x3a, 0x7b, 0x11, 0x70, 0x3d, 0x47, 0x44, 0x7b, 0xc7, 0x86, 0x5c, 0xcb, 0xde, 0x0d,
+                0xd8, 0x26, 0xde, 0x42, 0x61, 0xd6, 0xb0, 0xab, 0x6f, 0x77, 0x54, 0xf2, 0xa5, 0x80,
+                0x9d, 0x3e, 0x0b, 0x0a, 0x5c, 0xb9, 0x28, 0xc6, 0x77, 0x62, 0x31, 0x8d, 0x66, 0xbb,
+                0x8c, 0xb3, 0x62, 0xd2, 0xc0, 0x95, 0x84, 0x1d, 0x07, 0x55, 0x7b, 0x1b, 0xc4, 0xc7,
+                0x2a, 0x4f,
+            ]
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Snapshots of the state of the seeded CTR_DRBG.
+
+use core::{fmt, marker::PhantomData};
+
+use crate::{CtrDrbgCipher, SeededCtrDrbg, KEY_LENGTH, V_LENGTH};
+
+/// Snapshot of the state of a [SeededCtrDrbg]
+///
+/// A checkpoint captures the key, V, the reseed counter and the position of
+/// the RNG. Restoring it rewinds the RNG, e.g., to replay the operations of a
+/// KAT count:
+/// ```
+/// use nist_pqc_seeded_rng::{NistPqcAes256CtrRng, RngCore, Seed, SeedableRng};
+///
+/// let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+/// let checkpoint = rng.checkpoint();
+/// let first = rng.next_u64();
+/// rng.restore(&checkpoint);
+/// assert_eq!(rng.next_u64(), first);
+/// ```
+///
+/// The strict mode and the [CompatMode](crate::CompatMode) are not part of the
+/// checkpoint.
+#[cfg_attr(feature = "zeroize", derive(zeroize::ZeroizeOnDrop))]
+pub struct Checkpoint<C: CtrDrbgCipher> {
+    key: [u8; KEY_LENGTH],
+    v: [u8; V_LENGTH],
+    reseed_counter: u64,
+    position: u64,
+    last_request_length: usize,
+    #[cfg_attr(feature = "zeroize", zeroize(skip))]
+    cipher: PhantomData<C>,
+}
+
+impl<C: CtrDrbgCipher> Clone for Checkpoint<C> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key,
+            v: self.v,
+            reseed_counter: self.reseed_counter,
+            position: self.position,
+            last_request_length: self.last_request_length,
+            cipher: PhantomData,
+        }
+    }
+}
+
+impl<C: CtrDrbgCipher> fmt::Debug for Checkpoint<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Checkpoint")
+            .field("reseed_counter", &self.reseed_counter)
+            .field("position", &self.position)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: CtrDrbgCipher> SeededCtrDrbg<C> {
+    /// Take a snapshot of the state of the RNG.
+    pub fn checkpoint(&self) -> Checkpoint<C> {
+        Checkpoint {
+            key: self.key,
+            v: self.v,
+            reseed_counter: self.reseed_counter,
+            position: self.position,
+            last_request_length: self.last_request_length,
+            cipher: PhantomData,
+        }
+    }
+
+    /// Restore the state of the RNG from a snapshot.
+    ///
+    /// Afterwards, the RNG produces the same output as it did after the
+    /// snapshot was taken.
+    pub fn restore(&mut self, checkpoint: &Checkpoint<C>) {
+        self.key = checkpoint.key;
+        self.v = checkpoint.v;
+        self.reseed_counter = checkpoint.reseed_counter;
+        self.position = checkpoint.position;
+        self.last_request_length = checkpoint.last_request_length;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand_core::{RngCore, SeedableRng};
+
+    use crate::{NistPqcAes256CtrRng, Seed};
+
+    #[test]
+    fn restore() {
+        let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+        rng.next_u32();
+        let checkpoint = rng.checkpoint();
+        let mut expected = [0; 100];
+        rng.fill_bytes(&mut expected);
+        let reseed_counter = rng.reseed_counter();
+
+        rng.reseed(&Seed::default(), Some(b"replay"));
+        rng.restore(&checkpoint);
+        assert_eq!(rng.position(), 4);
+        assert_eq!(rng.reseed_counter(), 2);
+        let mut buf = [0; 100];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf, expected);
+        assert_eq!(rng.reseed_counter(), reseed_counter);
+    }
+
+    #[test]
+    fn restore_other_instance() {
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::from([1; 48]));
+        rng_2.restore(&rng_1.checkpoint());
+        assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+    }
+}
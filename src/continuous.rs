@@ -0,0 +1,183 @@
+//! Variant of the seeded CTR_DRBG buffering the remainder of the last block.
+
+use core::fmt;
+
+use aes::Aes256;
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+use crate::{CtrDrbgCipher, SeededCtrDrbg, V_LENGTH};
+
+/// [SeededCtrDrbg] buffering the remainder of the last block of a request
+///
+/// Like `rng.c`, [SeededCtrDrbg] discards the unused bytes of the last block
+/// of the keystream if the length of a request is not a multiple of the block
+/// length. This RNG instead buffers these bytes and serves them first on the
+/// next request. Only the bytes not served from the buffer are requested from
+/// the DRBG, which generates them and updates its state with
+/// `CTR_DRBG_Update` as usual.
+///
+/// Warning: After a request whose length is not a multiple of the block
+/// length, the output diverges from `rng.c` and does not reproduce the NIST
+/// KATs. Do not use this RNG for anything else than testing.
+#[cfg_attr(feature = "zeroize", derive(zeroize::ZeroizeOnDrop))]
+pub struct ContinuousMode<C: CtrDrbgCipher = Aes256> {
+    rng: SeededCtrDrbg<C>,
+    // the buffered bytes are stored at the end of the block
+    buffer: [u8; V_LENGTH],
+    buffered: usize,
+}
+
+impl<C: CtrDrbgCipher> ContinuousMode<C> {
+    /// Buffer the remainders of the blocks generated by `rng`.
+    pub const fn new(rng: SeededCtrDrbg<C>) -> Self {
+        Self {
+            rng,
+            buffer: [0; V_LENGTH],
+            buffered: 0,
+        }
+    }
+
+    /// Number of bytes left in the buffer.
+    pub fn buffered(&self) -> usize {
+        self.buffered
+    }
+}
+
+impl<C: CtrDrbgCipher> fmt::Debug for ContinuousMode<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContinuousMode").finish_non_exhaustive()
+    }
+}
+
+impl<C: CtrDrbgCipher> From<SeededCtrDrbg<C>> for ContinuousMode<C> {
+    fn from(value: SeededCtrDrbg<C>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<C: CtrDrbgCipher> SeedableRng for ContinuousMode<C> {
+    type Seed = C::Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(SeededCtrDrbg::from_seed(seed))
+    }
+
+    /// Instantiate the RNG from a `u64`.
+    ///
+    /// The seed is derived as for [SeededCtrDrbg].
+    fn seed_from_u64(state: u64) -> Self {
+        Self::new(SeededCtrDrbg::seed_from_u64(state))
+    }
+}
+
+impl<C, const N: usize> From<[u8; N]> for ContinuousMode<C>
+where
+    C: CtrDrbgCipher,
+    C::Seed: From<[u8; N]>,
+{
+    fn from(value: [u8; N]) -> Self {
+        Self::from_seed(value.into())
+    }
+}
+
+impl<C: CtrDrbgCipher> RngCore for ContinuousMode<C> {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let length = self.buffered.min(dest.len());
+        let start = V_LENGTH - self.buffered;
+        dest[..length].copy_from_slice(&self.buffer[start..start + length]);
+        self.buffered -= length;
+
+        let dest = &mut dest[length..];
+        if dest.is_empty() {
+            return;
+        }
+        self.buffered = (V_LENGTH - dest.len() % V_LENGTH) % V_LENGTH;
+        self.rng
+            .try_generate_with_tail(dest, &mut self.buffer[V_LENGTH - self.buffered..])
+            .expect("the DRBG is not strict");
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl<C: CtrDrbgCipher> CryptoRng for ContinuousMode<C> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{NistPqcAes256CtrRng, Seed};
+
+    const KEYSTREAM: [u8; 48] = [
+        0x91, 0x61, 0x8f, 0xe9, 0x9a, 0x8f, 0x94, 0x20, 0x49, 0x7b, 0x24, 0x6f, 0x73, 0x5b, 0x27,
+        0xa0, 0x19, 0x07, 0x8a, 0x9d, 0x3c, 0xa6, 0xb2, 0xa0, 0x01, 0xae, 0xc0, 0xb9, 0xe0, 0x7e,
+        0x68, 0x0b, 0xaf, 0x44, 0x43, 0x92, 0x2a, 0x11, 0x91, 0x78, 0xfb, 0x81, 0x91, 0xd4, 0xc9,
+        0xd0, 0xa5, 0x8f,
+    ];
+
+    #[test]
+    fn all_zeros() {
+        let mut rng = ContinuousMode::<Aes256>::from_seed(Seed::default());
+        let mut buf = [0xff; 48];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf, KEYSTREAM);
+        assert_eq!(rng.buffered(), 0);
+    }
+
+    #[test]
+    fn split_requests() {
+        let mut rng = ContinuousMode::<Aes256>::from([0; 48]);
+        let mut buf = [0; 48];
+        rng.fill_bytes(&mut buf[..5]);
+        assert_eq!(rng.buffered(), 11);
+        rng.fill_bytes(&mut buf[5..21]);
+        assert_eq!(rng.buffered(), 11);
+        rng.fill_bytes(&mut buf[21..]);
+        assert_eq!(rng.buffered(), 0);
+
+        // the requests to the DRBG are 5, 5 and 16 bytes long, each using a
+        // full block of the keystream
+        let mut reference = NistPqcAes256CtrRng::from([0; 48]);
+        let mut expected = [0; 48];
+        reference.fill_bytes(&mut expected[..16]);
+        reference.fill_bytes(&mut expected[16..32]);
+        reference.fill_bytes(&mut expected[32..]);
+        assert_eq!(buf, expected);
+        assert_eq!(buf[..16], KEYSTREAM[..16]);
+    }
+
+    #[test]
+    fn first_request() {
+        let mut rng_1 = ContinuousMode::<Aes256>::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+        assert_ne!(rng_1.next_u64(), rng_2.next_u64());
+    }
+
+    #[test]
+    fn aligned_requests() {
+        let mut rng_1 = ContinuousMode::<Aes256>::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut buf_1 = [0; 32];
+        let mut buf_2 = [0; 32];
+        for _ in 0..3 {
+            rng_1.fill_bytes(&mut buf_1);
+            rng_2.fill_bytes(&mut buf_2);
+            assert_eq!(buf_1, buf_2);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! RNG wrapper collecting statistics of the requests.
+
+use alloc::vec::Vec;
+
+use rand_core::{CryptoRng, RngCore};
+
+/// Statistics of the requests of a [CountingRng]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Number of requests.
+    pub calls: usize,
+    /// Total number of bytes drawn.
+    pub bytes: usize,
+    /// Number of bytes drawn by each request in order.
+    pub request_lengths: Vec<usize>,
+}
+
+impl Stats {
+    fn count(&mut self, length: usize) {
+        self.calls += 1;
+        self.bytes += length;
+        self.request_lengths.push(length);
+    }
+}
+
+/// RNG counting the requests to the wrapped RNG
+///
+/// The requests are counted in total and per phase, e.g., per KAT operation:
+/// ```
+/// use nist_pqc_seeded_rng::{
+///     counting::CountingRng, NistPqcAes256CtrRng, RngCore, Seed, SeedableRng,
+/// };
+///
+/// let mut rng = CountingRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()));
+/// rng.set_phase("keygen");
+/// rng.fill_bytes(&mut [0; 32]);
+/// rng.fill_bytes(&mut [0; 32]);
+/// rng.set_phase("encaps");
+/// rng.fill_bytes(&mut [0; 32]);
+///
+/// assert_eq!(rng.stats("keygen").unwrap().request_lengths, [32, 32]);
+/// assert_eq!(rng.stats("encaps").unwrap().bytes, 32);
+/// assert_eq!(rng.total().calls, 3);
+/// ```
+///
+/// Requests before the first call to [set_phase](Self::set_phase) are counted
+/// in the phase `""`. Failed requests are not counted.
+#[derive(Clone, Debug)]
+pub struct CountingRng<R: RngCore> {
+    rng: R,
+    phase: &'static str,
+    phases: Vec<(&'static str, Stats)>,
+    total: Stats,
+}
+
+impl<R: RngCore> CountingRng<R> {
+    /// Count the requests to `rng`.
+    pub const fn new(rng: R) -> Self {
+        Self {
+            rng,
+            phase: "",
+            phases: Vec::new(),
+            total: Stats {
+                calls: 0,
+                bytes: 0,
+                request_lengths: Vec::new(),
+            },
+        }
+    }
+
+    /// Count the following requests in the phase `label`.
+    ///
+    /// Switching back to an earlier phase continues its statistics.
+    pub fn set_phase(&mut self, label: &'static str) {
+        self.phase = label;
+    }
+
+    /// The label of the current phase.
+    pub const fn phase(&self) -> &'static str {
+        self.phase
+    }
+
+    /// Statistics of the phase `label` or `None` if no request has been
+    /// counted in the phase.
+    pub fn stats(&self, label: &str) -> Option<&Stats> {
+        self.phases
+            .iter()
+            .find(|(phase, _)| *phase == label)
+            .map(|(_, stats)| stats)
+    }
+
+    /// Iterate over the statistics of all phases in the order of their first
+    /// request.
+    pub fn phases(&self) -> impl ExactSizeIterator<Item = (&'static str, &Stats)> + '_ {
+        self.phases.iter().map(|(phase, stats)| (*phase, stats))
+    }
+
+    /// Statistics of all requests.
+    pub const fn total(&self) -> &Stats {
+        &self.total
+    }
+
+    /// Return the wrapped RNG.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+
+    fn count(&mut self, length: usize) {
+        self.total.count(length);
+        let phase = self.phase;
+        match self.phases.iter_mut().find(|(label, _)| *label == phase) {
+            Some((_, stats)) => stats.count(length),
+            None => {
+                let mut stats = Stats::default();
+                stats.count(length);
+                self.phases.push((phase, stats));
+            }
+        }
+    }
+}
+
+impl<R: RngCore> RngCore for CountingRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        let value = self.rng.next_u32();
+        self.count(4);
+        value
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let value = self.rng.next_u64();
+        self.count(8);
+        value
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+        self.count(dest.len());
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.rng.try_fill_bytes(dest)?;
+        self.count(dest.len());
+        Ok(())
+    }
+}
+
+impl<R: RngCore + CryptoRng> CryptoRng for CountingRng<R> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ctr_drbg, NistPqcAes256CtrRng, Seed};
+
+    #[test]
+    fn phases() {
+        let mut rng = CountingRng::new(NistPqcAes256CtrRng::from_seed_strict(Seed::default()));
+        rng.next_u32();
+        rng.set_phase("a");
+        rng.next_u64();
+        rng.set_phase("b");
+        rng.fill_bytes(&mut [0; 5]);
+        assert!(rng
+            .try_fill_bytes(&mut [0; ctr_drbg::MAX_BYTES_PER_REQUEST + 1])
+            .is_err());
+        rng.set_phase("a");
+        rng.fill_bytes(&mut [0; 3]);
+        assert_eq!(rng.phase(), "a");
+
+        assert_eq!(
+            rng.stats("a"),
+            Some(&Stats {
+                calls: 2,
+                bytes: 11,
+                request_lengths: [8, 3].into()
+            })
+        );
+        assert!(rng.stats("c").is_none());
+        let phases: Vec<_> = rng
+            .phases()
+            .map(|(label, stats)| (label, stats.calls))
+            .collect();
+        assert_eq!(phases, [("", 1), ("a", 2), ("b", 1)]);
+        assert_eq!(rng.total().request_lengths, [4, 8, 5, 3]);
+        assert_eq!(rng.total().bytes, 20);
+        assert_eq!(rng.into_inner().reseed_counter(), 5);
+    }
+}
@@ -0,0 +1,1042 @@
+//! CTR_DRBG as specified in NIST SP 800-90A Rev. 1 using AES and the block
+//! cipher derivation function.
+
+use core::fmt;
+
+use aes::{cipher::generic_array::GenericArray, Aes128, Aes192, Aes256};
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+use crate::{
+    drbg::{generate, update, Cipher},
+    guard::{self, ZeroizeGuard},
+    EntropySource, Error, Seed, KEY_LENGTH, SEED_LENGTH, V_LENGTH,
+};
+
+/// Maximum number of bytes that can be requested with a single call to
+/// [CtrDrbg::generate].
+pub const MAX_BYTES_PER_REQUEST: usize = 1 << 16;
+/// Maximum number of requests between reseeds.
+pub const RESEED_INTERVAL: u64 = 1 << 48;
+/// Maximum number of bytes that can be derived with a single call to
+/// [block_cipher_df].
+pub const MAX_DF_OUTPUT_LENGTH: usize = 64;
+
+/// Security strengths supported by [CtrDrbg]
+///
+/// The security strength determines the AES variant used by the DRBG.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SecurityStrength {
+    /// 128 bit security using AES-128
+    Bits128,
+    /// 192 bit security using AES-192
+    Bits192,
+    /// 256 bit security using AES-256
+    #[default]
+    Bits256,
+}
+
+impl SecurityStrength {
+    /// Length of the AES key in bytes.
+    pub const fn key_length(self) -> usize {
+        match self {
+            Self::Bits128 => 16,
+            Self::Bits192 => 24,
+            Self::Bits256 => 32,
+        }
+    }
+
+    /// Length of the seed in bytes, i.e., the length of the AES key and the
+    /// block length.
+    pub const fn seed_length(self) -> usize {
+        self.key_length() + V_LENGTH
+    }
+}
+
+impl From<SecurityStrength> for u32 {
+    fn from(value: SecurityStrength) -> Self {
+        match value {
+            SecurityStrength::Bits128 => 128,
+            SecurityStrength::Bits192 => 192,
+            SecurityStrength::Bits256 => 256,
+        }
+    }
+}
+
+impl TryFrom<u32> for SecurityStrength {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            128 => Ok(Self::Bits128),
+            192 => Ok(Self::Bits192),
+            256 => Ok(Self::Bits256),
+            _ => Err(Error::UnsupportedSecurityStrength),
+        }
+    }
+}
+
+/// Incremental computation of `BCC` (cf. SP 800-90A, section 10.3.3).
+struct Bcc<'a, C> {
+    cipher: &'a C,
+    chaining_value: [u8; V_LENGTH],
+    position: usize,
+}
+
+impl<'a, C: Cipher> Bcc<'a, C> {
+    fn new(cipher: &'a C) -> Self {
+        Self {
+            cipher,
+            chaining_value: [0; V_LENGTH],
+            position: 0,
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            self.chaining_value[self.position] ^= byte;
+            self.position += 1;
+            if self.position == V_LENGTH {
+                self.cipher
+                    .encrypt_block(GenericArray::from_mut_slice(&mut self.chaining_value));
+                self.position = 0;
+            }
+        }
+    }
+
+    fn finalize(self, output: &mut [u8]) {
+        // the caller pads the input to a multiple of the block length
+        debug_assert_eq!(self.position, 0);
+        output.copy_from_slice(&self.chaining_value);
+    }
+}
+
+impl<C> Drop for Bcc<'_, C> {
+    fn drop(&mut self) {
+        guard::zeroize(&mut self.chaining_value);
+    }
+}
+
+/// `Block_Cipher_df` (cf. SP 800-90A, section 10.3.2) applied to the
+/// concatenation of `inputs`.
+pub(crate) fn df<C: Cipher>(inputs: &[&[u8]], output: &mut [u8]) {
+    let key_length = C::key_size();
+    let input_length: usize = inputs.iter().map(|input| input.len()).sum();
+    let padding = (V_LENGTH - (9 + input_length) % V_LENGTH) % V_LENGTH;
+
+    let mut df_key = [0; KEY_LENGTH];
+    df_key
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, byte)| *byte = i as u8);
+    let cipher = C::new(GenericArray::from_slice(&df_key[..key_length]));
+
+    let mut temp = [0; SEED_LENGTH];
+    let mut temp = ZeroizeGuard::new(&mut temp);
+    let temp_length = (key_length + V_LENGTH).div_ceil(V_LENGTH) * V_LENGTH;
+    for (i, chunk) in temp[..temp_length].chunks_exact_mut(V_LENGTH).enumerate() {
+        let mut bcc = Bcc::new(&cipher);
+        let mut iv = [0; V_LENGTH];
+        iv[..4].copy_from_slice(&(i as u32).to_be_bytes());
+        bcc.update(&iv);
+        bcc.update(&(input_length as u32).to_be_bytes());
+        bcc.update(&(output.len() as u32).to_be_bytes());
+        inputs.iter().for_each(|input| bcc.update(input));
+        bcc.update(&[0x80]);
+        (0..padding).for_each(|_| bcc.update(&[0]));
+        bcc.finalize(chunk);
+    }
+
+    let cipher = C::new(GenericArray::from_slice(&temp[..key_length]));
+    let mut x = [0; V_LENGTH];
+    let mut x = ZeroizeGuard::new(&mut x);
+    x.copy_from_slice(&temp[key_length..key_length + V_LENGTH]);
+    for chunk in output.chunks_mut(V_LENGTH) {
+        cipher.encrypt_block(GenericArray::from_mut_slice(&mut x));
+        chunk.copy_from_slice(&x[..chunk.len()]);
+    }
+}
+
+/// Block cipher derivation function `Block_Cipher_df` from NIST SP 800-90A
+///
+/// Derives `output.len()` bytes from `input` using the AES variant of the
+/// given security strength. Fails if more than [MAX_DF_OUTPUT_LENGTH] bytes
+/// are requested.
+pub fn block_cipher_df(
+    security_strength: SecurityStrength,
+    input: &[u8],
+    output: &mut [u8],
+) -> Result<(), Error> {
+    if output.len() > MAX_DF_OUTPUT_LENGTH {
+        return Err(Error::RequestTooLarge);
+    }
+
+    match security_strength {
+        SecurityStrength::Bits128 => df::<Aes128>(&[input], output),
+        SecurityStrength::Bits192 => df::<Aes192>(&[input], output),
+        SecurityStrength::Bits256 => df::<Aes256>(&[input], output),
+    }
+    Ok(())
+}
+
+/// Update function `CTR_DRBG_Update` from NIST SP 800-90A
+///
+/// Updates `key` and `v` with the provided data. The AES variant is selected
+/// by the length of the key. Fails if the key is not 16, 24 or 32 bytes long.
+///
+/// # Panics
+///
+/// Panics if the provided data is not exactly as long as the key and `v`
+/// combined.
+pub fn ctr_drbg_update(
+    key: &mut [u8],
+    v: &mut [u8; V_LENGTH],
+    provided_data: &[u8],
+) -> Result<(), Error> {
+    assert_eq!(
+        provided_data.len(),
+        key.len() + V_LENGTH,
+        "provided data of invalid length"
+    );
+
+    match key.len() {
+        16 => update::<Aes128>(key, v, provided_data),
+        24 => update::<Aes192>(key, v, provided_data),
+        32 => update::<Aes256>(key, v, provided_data),
+        _ => return Err(Error::UnsupportedSecurityStrength),
+    }
+    Ok(())
+}
+
+/// CTR_DRBG using AES and the derivation function
+///
+/// In contrast to [NistPqcAes256CtrRng](crate::NistPqcAes256CtrRng), this RNG
+/// implements the full instantiate, reseed and generate functions from NIST SP
+/// 800-90A including the derivation function and the reseed counter.
+#[derive(Debug)]
+#[cfg_attr(feature = "zeroize", derive(zeroize::ZeroizeOnDrop))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CtrDrbg {
+    key: [u8; KEY_LENGTH],
+    v: [u8; V_LENGTH],
+    reseed_counter: u64,
+    #[cfg_attr(feature = "zeroize", zeroize(skip))]
+    security_strength: SecurityStrength,
+}
+
+impl CtrDrbg {
+    /// Instantiate the DRBG using AES-256 from an entropy input (that may
+    /// include a nonce) and a personalization string.
+    pub fn new(entropy_input: &[u8], personalization_string: &[u8]) -> Self {
+        Self::with_security_strength(
+            entropy_input,
+            personalization_string,
+            SecurityStrength::Bits256,
+        )
+    }
+
+    /// Instantiate the DRBG for the given security strength from an entropy
+    /// input (that may include a nonce) and a personalization string.
+    pub fn with_security_strength(
+        entropy_input: &[u8],
+        personalization_string: &[u8],
+        security_strength: SecurityStrength,
+    ) -> Self {
+        let mut rng = Self {
+            key: [0; KEY_LENGTH],
+            v: [0; V_LENGTH],
+            reseed_counter: 1,
+            security_strength,
+        };
+        rng.update_seed_material(&[entropy_input, personalization_string]);
+        rng
+    }
+
+    /// Start building a DRBG from an entropy input, a nonce and a
+    /// personalization string.
+    pub fn builder(entropy_input: &[u8]) -> CtrDrbgBuilder<'_> {
+        CtrDrbgBuilder::new(entropy_input)
+    }
+
+    /// Instantiate the DRBG like `randombytes_init` with a security strength
+    /// given in bits.
+    ///
+    /// Fails if the security strength is not one of 128, 192 or 256.
+    pub fn randombytes_init(
+        entropy_input: &[u8],
+        personalization_string: &[u8],
+        security_strength: u32,
+    ) -> Result<Self, Error> {
+        SecurityStrength::try_from(security_strength).map(|security_strength| {
+            Self::with_security_strength(entropy_input, personalization_string, security_strength)
+        })
+    }
+
+    /// Security strength of the DRBG.
+    pub fn security_strength(&self) -> SecurityStrength {
+        self.security_strength
+    }
+
+    /// Reseed the DRBG with a fresh entropy input and additional input.
+    pub fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) {
+        self.update_seed_material(&[entropy_input, additional_input]);
+        self.reseed_counter = 1;
+    }
+
+    /// Fill `dest` with random bytes taking the additional input into account.
+    ///
+    /// Fails if more than [MAX_BYTES_PER_REQUEST] bytes are requested or if
+    /// the DRBG needs to be reseeded.
+    pub fn generate(&mut self, dest: &mut [u8], additional_input: &[u8]) -> Result<(), Error> {
+        if self.reseed_counter > RESEED_INTERVAL {
+            return Err(Error::ReseedRequired);
+        }
+        if dest.len() > MAX_BYTES_PER_REQUEST {
+            return Err(Error::RequestTooLarge);
+        }
+
+        let key_length = self.security_strength.key_length();
+        let seed_length = self.security_strength.seed_length();
+        let key = &mut self.key[..key_length];
+        let v = &mut self.v;
+        let mut additional_input_block = [0; SEED_LENGTH];
+        let mut additional_input_block =
+            ZeroizeGuard::new(&mut additional_input_block[..seed_length]);
+        if !additional_input.is_empty() {
+            match self.security_strength {
+                SecurityStrength::Bits128 => {
+                    df::<Aes128>(&[additional_input], &mut additional_input_block);
+                    update::<Aes128>(key, v, &additional_input_block);
+                }
+                SecurityStrength::Bits192 => {
+                    df::<Aes192>(&[additional_input], &mut additional_input_block);
+                    update::<Aes192>(key, v, &additional_input_block);
+                }
+                SecurityStrength::Bits256 => {
+                    df::<Aes256>(&[additional_input], &mut additional_input_block);
+                    update::<Aes256>(key, v, &additional_input_block);
+                }
+            }
+        }
+
+        match self.security_strength {
+            SecurityStrength::Bits128 => generate::<Aes128>(key, v, dest, &additional_input_block),
+            SecurityStrength::Bits192 => generate::<Aes192>(key, v, dest, &additional_input_block),
+            SecurityStrength::Bits256 => generate::<Aes256>(key, v, dest, &additional_input_block),
+        }
+        self.reseed_counter += 1;
+        Ok(())
+    }
+
+    /// Derive seed material from the inputs and update the state with it.
+    fn update_seed_material(&mut self, inputs: &[&[u8]]) {
+        let key = &mut self.key[..self.security_strength.key_length()];
+        let v = &mut self.v;
+        let mut seed_material = [0; SEED_LENGTH];
+        let mut seed_material =
+            ZeroizeGuard::new(&mut seed_material[..self.security_strength.seed_length()]);
+        match self.security_strength {
+            SecurityStrength::Bits128 => {
+                df::<Aes128>(inputs, &mut seed_material);
+                update::<Aes128>(key, v, &seed_material);
+            }
+            SecurityStrength::Bits192 => {
+                df::<Aes192>(inputs, &mut seed_material);
+                update::<Aes192>(key, v, &seed_material);
+            }
+            SecurityStrength::Bits256 => {
+                df::<Aes256>(inputs, &mut seed_material);
+                update::<Aes256>(key, v, &seed_material);
+            }
+        }
+    }
+}
+
+/// Builder assembling the seed material `entropy_input || nonce ||
+/// personalization_string` to instantiate a [CtrDrbg]
+///
+/// ```
+/// use nist_pqc_seeded_rng::{ctr_drbg::CtrDrbgBuilder, SecurityStrength};
+///
+/// let rng = CtrDrbgBuilder::new(&[0; 32])
+///     .nonce(&[1; 16])
+///     .personalization_string(b"personalization")
+///     .security_strength(SecurityStrength::Bits256)
+///     .build();
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CtrDrbgBuilder<'a> {
+    entropy_input: &'a [u8],
+    nonce: &'a [u8],
+    personalization_string: &'a [u8],
+    security_strength: SecurityStrength,
+}
+
+impl<'a> CtrDrbgBuilder<'a> {
+    /// Create a new builder from an entropy input using AES-256 without nonce
+    /// and personalization string.
+    pub const fn new(entropy_input: &'a [u8]) -> Self {
+        Self {
+            entropy_input,
+            nonce: &[],
+            personalization_string: &[],
+            security_strength: SecurityStrength::Bits256,
+        }
+    }
+
+    /// Set the nonce.
+    pub fn nonce(mut self, nonce: &'a [u8]) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    /// Set the personalization string.
+    pub fn personalization_string(mut self, personalization_string: &'a [u8]) -> Self {
+        self.personalization_string = personalization_string;
+        self
+    }
+
+    /// Set the security strength.
+    pub fn security_strength(mut self, security_strength: SecurityStrength) -> Self {
+        self.security_strength = security_strength;
+        self
+    }
+
+    /// Instantiate the DRBG.
+    pub fn build(self) -> CtrDrbg {
+        let mut rng = CtrDrbg {
+            key: [0; KEY_LENGTH],
+            v: [0; V_LENGTH],
+            reseed_counter: 1,
+            security_strength: self.security_strength,
+        };
+        rng.update_seed_material(&[self.entropy_input, self.nonce, self.personalization_string]);
+        rng
+    }
+}
+
+impl SeedableRng for CtrDrbg {
+    type Seed = Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(seed.as_ref(), &[])
+    }
+
+    /// Instantiate the DRBG from a `u64`.
+    ///
+    /// The little-endian encoding of `state` is used as entropy input with an
+    /// empty personalization string.
+    fn seed_from_u64(state: u64) -> Self {
+        Self::new(&state.to_le_bytes(), &[])
+    }
+}
+
+impl RngCore for CtrDrbg {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).expect("reseed required")
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        for chunk in dest.chunks_mut(MAX_BYTES_PER_REQUEST) {
+            self.generate(chunk, &[])?;
+        }
+        Ok(())
+    }
+}
+
+impl CryptoRng for CtrDrbg {}
+
+/// [CtrDrbg] obtaining its entropy inputs from an [EntropySource]
+///
+/// If prediction resistance is enabled, the DRBG is reseeded with fresh
+/// entropy before every request. Otherwise, it is only reseeded once the
+/// reseed interval is exceeded. Prediction resistance is enabled by default.
+pub struct PredictionResistantCtrDrbg<E: EntropySource> {
+    drbg: CtrDrbg,
+    entropy_source: E,
+    prediction_resistance: bool,
+}
+
+impl<E: EntropySource> PredictionResistantCtrDrbg<E> {
+    /// Instantiate the DRBG using AES-256 with an entropy input and nonce from
+    /// the entropy source and a personalization string.
+    pub fn new(entropy_source: E, personalization_string: &[u8]) -> Result<Self, Error> {
+        Self::with_security_strength(
+            entropy_source,
+            personalization_string,
+            SecurityStrength::Bits256,
+        )
+    }
+
+    /// Instantiate the DRBG for the given security strength with an entropy
+    /// input and nonce from the entropy source and a personalization string.
+    pub fn with_security_strength(
+        mut entropy_source: E,
+        personalization_string: &[u8],
+        security_strength: SecurityStrength,
+    ) -> Result<Self, Error> {
+        // entropy input followed by a nonce of half the security strength
+        let key_length = security_strength.key_length();
+        let mut entropy_input = [0; KEY_LENGTH + KEY_LENGTH / 2];
+        let mut entropy_input =
+            ZeroizeGuard::new(&mut entropy_input[..key_length + key_length / 2]);
+        entropy_source.fill_entropy(&mut entropy_input)?;
+        let drbg = CtrDrbg::with_security_strength(
+            &entropy_input,
+            personalization_string,
+            security_strength,
+        );
+        Ok(Self {
+            drbg,
+            entropy_source,
+            prediction_resistance: true,
+        })
+    }
+
+    /// Security strength of the DRBG.
+    pub fn security_strength(&self) -> SecurityStrength {
+        self.drbg.security_strength()
+    }
+
+    /// Returns whether prediction resistance is enabled.
+    pub fn prediction_resistance(&self) -> bool {
+        self.prediction_resistance
+    }
+
+    /// Enable or disable prediction resistance.
+    pub fn set_prediction_resistance(&mut self, prediction_resistance: bool) {
+        self.prediction_resistance = prediction_resistance;
+    }
+
+    /// Reseed the DRBG with fresh entropy from the entropy source and the
+    /// additional input.
+    pub fn reseed(&mut self, additional_input: &[u8]) -> Result<(), Error> {
+        let mut entropy_input = [0; KEY_LENGTH];
+        let mut entropy_input =
+            ZeroizeGuard::new(&mut entropy_input[..self.drbg.security_strength().key_length()]);
+        self.entropy_source.fill_entropy(&mut entropy_input)?;
+        self.drbg.reseed(&entropy_input, additional_input);
+        Ok(())
+    }
+
+    /// Fill `dest` with random bytes taking the additional input into account.
+    ///
+    /// Fails if more than [MAX_BYTES_PER_REQUEST] bytes are requested or if
+    /// the entropy source fails.
+    pub fn generate(&mut self, dest: &mut [u8], additional_input: &[u8]) -> Result<(), Error> {
+        if dest.len() > MAX_BYTES_PER_REQUEST {
+            return Err(Error::RequestTooLarge);
+        }
+
+        if self.prediction_resistance {
+            self.reseed(additional_input)?;
+            return self.drbg.generate(dest, &[]);
+        }
+        match self.drbg.generate(dest, additional_input) {
+            Err(Error::ReseedRequired) => {
+                self.reseed(additional_input)?;
+                self.drbg.generate(dest, &[])
+            }
+            result => result,
+        }
+    }
+
+    /// Return the entropy source.
+    pub fn into_entropy_source(self) -> E {
+        self.entropy_source
+    }
+}
+
+impl<E: EntropySource> fmt::Debug for PredictionResistantCtrDrbg<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PredictionResistantCtrDrbg")
+            .field("drbg", &self.drbg)
+            .field("prediction_resistance", &self.prediction_resistance)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<E: EntropySource> RngCore for PredictionResistantCtrDrbg<E> {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).expect("entropy source failed")
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        for chunk in dest.chunks_mut(MAX_BYTES_PER_REQUEST) {
+            self.generate(chunk, &[])?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: EntropySource> CryptoRng for PredictionResistantCtrDrbg<E> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ENTROPY_INPUT: [u8; SEED_LENGTH] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+        0x1e, 0x1f, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c,
+        0x2d, 0x2e, 0x2f,
+    ];
+
+    fn range<const N: usize>(start: u8) -> [u8; N] {
+        let mut buf = [0; N];
+        buf.iter_mut()
+            .enumerate()
+            .for_each(|(i, byte)| *byte = start + i as u8);
+        buf
+    }
+
+    // The expected values were cross-checked against the CTR-DRBG of OpenSSL 3
+    // unless the test uses a CAVP vector.
+
+    /// CAVP CTR_DRBG vector (AES-256 use df, no reseed, COUNT = 0)
+    #[test]
+    fn cavp_no_additional_input() {
+        let entropy_input = [
+            0x36, 0x40, 0x19, 0x40, 0xfa, 0x8b, 0x1f, 0xba, 0x91, 0xa1, 0x66, 0x1f, 0x21, 0x1d,
+            0x78, 0xa0, 0xb9, 0x38, 0x9a, 0x74, 0xe5, 0xbc, 0xcf, 0xec, 0xe8, 0xd7, 0x66, 0xaf,
+            0x1a, 0x6d, 0x3b, 0x14,
+        ];
+        let nonce = [
+            0x49, 0x6f, 0x25, 0xb0, 0xf1, 0x30, 0x1b, 0x4f, 0x50, 0x1b, 0xe3, 0x03, 0x80, 0xa1,
+            0x37, 0xeb,
+        ];
+        let mut rng = CtrDrbg::builder(&entropy_input).nonce(&nonce).build();
+        let mut buf = [0; 64];
+        rng.generate(&mut buf, &[]).unwrap();
+        rng.generate(&mut buf, &[]).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x58, 0x62, 0xeb, 0x38, 0xbd, 0x55, 0x8d, 0xd9, 0x78, 0xa6, 0x96, 0xe6, 0xdf, 0x16,
+                0x47, 0x82, 0xdd, 0xd8, 0x87, 0xe7, 0xe9, 0xa6, 0xc9, 0xf3, 0xf1, 0xfb, 0xaf, 0xb7,
+                0x89, 0x41, 0xb5, 0x35, 0xa6, 0x49, 0x12, 0xdf, 0xd2, 0x24, 0xc6, 0xdc, 0x74, 0x54,
+                0xe5, 0x25, 0x0b, 0x3d, 0x97, 0x16, 0x5e, 0x16, 0x26, 0x0c, 0x2f, 0xaf, 0x1c, 0xc7,
+                0x73, 0x5c, 0xb7, 0x5f, 0xb4, 0xf0, 0x7e, 0x1d,
+            ]
+        );
+    }
+
+    #[test]
+    fn no_additional_input() {
+        let mut rng = CtrDrbg::from_seed(ENTROPY_INPUT.into());
+        let mut buf = [0; 64];
+        rng.fill_bytes(&mut buf);
+        rng.fill_bytes(&mut buf);
+        assert_eq!(
+            buf,
+            [
+                0xc5, 0xb1, 0xae, 0x8d, 0xbc, 0x23, 0x05, 0x6b, 0x19, 0xcf, 0x88, 0xb1, 0x99, 0x7e,
+                0x84, 0x98, 0xb4, 0xb3, 0x94, 0xc0, 0xdb, 0x97, 0x60, 0xa3, 0x70, 0x4b, 0x0c, 0x1d,
+                0x6a, 0x4c, 0x92, 0x6e, 0x5b, 0xfe, 0x23, 0x4a, 0xfb, 0x31, 0xb4, 0x98, 0xa3, 0x08,
+                0x10, 0xbd, 0xb8, 0xd3, 0x54, 0x2b, 0x55, 0x30, 0x84, 0x9f, 0x8b, 0x9b, 0x8b, 0xea,
+                0x8c, 0xad, 0x70, 0xe6, 0x33, 0xf3, 0x2a, 0x24,
+            ]
+        );
+    }
+
+    #[test]
+    fn personalization_and_additional_input() {
+        let mut rng = CtrDrbg::new(&ENTROPY_INPUT, &range::<32>(0x40));
+        let mut buf = [0; 64];
+        rng.generate(&mut buf, &range::<32>(0x60)).unwrap();
+        rng.generate(&mut buf, &range::<32>(0xa0)).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x6d, 0x6a, 0x39, 0x52, 0xfd, 0x8c, 0x95, 0xe5, 0x51, 0xc4, 0xaa, 0xba, 0x57, 0xee,
+                0x48, 0xc2, 0x69, 0x9c, 0x6e, 0x14, 0xb0, 0x93, 0x5d, 0xd9, 0x8e, 0x29, 0x71, 0xf3,
+                0xfb, 0x7b, 0x79, 0x10, 0xb1, 0x2d, 0x93, 0x69, 0x26, 0xd2, 0x99, 0x1d, 0x27, 0x9f,
+                0x26, 0xf4, 0x9e, 0x2c, 0x12, 0xd8, 0x09, 0xed, 0x49, 0x45, 0x5b, 0x48, 0x45, 0xd9,
+                0x7d, 0x65, 0xcb, 0x0a, 0x4a, 0xa6, 0x8c, 0x16,
+            ]
+        );
+    }
+
+    #[test]
+    fn reseed() {
+        let mut rng = CtrDrbg::from_seed(ENTROPY_INPUT.into());
+        rng.reseed(&range::<32>(0x80), &range::<32>(0x60));
+        let mut buf = [0; 64];
+        rng.generate(&mut buf, &[]).unwrap();
+        rng.generate(&mut buf, &range::<32>(0xa0)).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0xf3, 0x1d, 0xeb, 0x12, 0x52, 0x7a, 0x2f, 0x39, 0xd4, 0x9d, 0xac, 0x7d, 0xf8, 0x73,
+                0xe6, 0xd2, 0xcd, 0x96, 0x1f, 0xe5, 0x8a, 0x6c, 0x80, 0xfd, 0x32, 0x8b, 0xf9, 0xcb,
+                0x77, 0x01, 0x1d, 0x0d, 0xd2, 0x62, 0x4b, 0x49, 0x08, 0xc2, 0xc1, 0xde, 0x5c, 0x87,
+                0x37, 0x0a, 0xa9, 0x83, 0xc2, 0x63, 0x9d, 0x34, 0x15, 0x90, 0x46, 0x10, 0xfd, 0x8c,
+                0x07, 0xe3, 0x64, 0xfb, 0xe6, 0x07, 0xc5, 0xaf,
+            ]
+        );
+    }
+
+    /// Inputs to the derivation function of `7 + 16 * k` bytes require no
+    /// padding, as they fill whole blocks together with L, N and the 0x80 byte
+    #[test]
+    fn block_boundaries() {
+        let input = range::<39>(0x40);
+        let mut output = [0; 32];
+        block_cipher_df(SecurityStrength::Bits128, &input[..7], &mut output).unwrap();
+        assert_eq!(
+            output,
+            [
+                0x60, 0x23, 0x23, 0x05, 0x9c, 0xbb, 0xf4, 0xe1, 0x54, 0xe1, 0xf9, 0x36, 0xa2, 0x0e,
+                0xcd, 0x9e, 0xa0, 0xc9, 0x5e, 0x92, 0x20, 0xb3, 0x46, 0x9f, 0xd0, 0x00, 0x20, 0x5b,
+                0xec, 0x62, 0x79, 0x5a,
+            ]
+        );
+        block_cipher_df(SecurityStrength::Bits128, &input[..23], &mut output).unwrap();
+        assert_eq!(
+            output,
+            [
+                0xc1, 0x70, 0x86, 0xc6, 0xab, 0xe5, 0x28, 0x88, 0xf8, 0x1b, 0xd3, 0xeb, 0xa4, 0xe1,
+                0xc2, 0x7e, 0xe6, 0x37, 0xff, 0x34, 0x4e, 0x1c, 0x0a, 0x12, 0xdb, 0xf4, 0x55, 0x8a,
+                0x37, 0xd2, 0x8e, 0xfb,
+            ]
+        );
+        block_cipher_df(SecurityStrength::Bits128, &input, &mut output).unwrap();
+        assert_eq!(
+            output,
+            [
+                0x61, 0x2d, 0xf1, 0xbe, 0x70, 0x68, 0xf9, 0xaf, 0x0d, 0x44, 0x23, 0x0d, 0x50, 0xa2,
+                0x60, 0x1f, 0x37, 0x94, 0xd9, 0xce, 0x89, 0x7d, 0x6a, 0xee, 0x4c, 0x2f, 0x0b, 0xed,
+                0xe7, 0xb7, 0x28, 0x12,
+            ]
+        );
+
+        let mut input = [0; SEED_LENGTH + 7];
+        input[..SEED_LENGTH].copy_from_slice(&ENTROPY_INPUT);
+        input[SEED_LENGTH..].copy_from_slice(&range::<7>(0x40));
+        let mut seed_material = [0; SEED_LENGTH];
+        block_cipher_df(SecurityStrength::Bits256, &input, &mut seed_material).unwrap();
+        assert_eq!(
+            seed_material,
+            [
+                0x84, 0x80, 0xa5, 0x67, 0x03, 0x32, 0xc5, 0xa7, 0x08, 0x3d, 0xae, 0x15, 0xff, 0x15,
+                0x80, 0x7e, 0x31, 0xaa, 0xc5, 0x00, 0xe5, 0xf3, 0x7c, 0x64, 0xc0, 0xa7, 0x1e, 0x19,
+                0x10, 0x7d, 0x4d, 0xde, 0x4c, 0xf0, 0x74, 0x70, 0xeb, 0x6c, 0xe2, 0x9c, 0x9e, 0x7b,
+                0x09, 0x59, 0xa9, 0x53, 0xc3, 0x80,
+            ]
+        );
+
+        for (length, expected) in [
+            (
+                7,
+                [
+                    0x1c, 0x71, 0x49, 0xc6, 0xbe, 0x2f, 0x34, 0x2d, 0xa6, 0xf3, 0xd4, 0xf5, 0xff,
+                    0x72, 0x24, 0x46, 0x11, 0xa3, 0x4c, 0x1b, 0x40, 0x0f, 0x43, 0x21, 0x77, 0x02,
+                    0xa8, 0x29, 0x9c, 0x75, 0xd8, 0xd0,
+                ],
+            ),
+            (
+                23,
+                [
+                    0x6f, 0xc9, 0x9a, 0x16, 0xce, 0x42, 0xe6, 0xf5, 0x30, 0x87, 0x22, 0x7f, 0x19,
+                    0xcc, 0x7f, 0xd0, 0xd3, 0x78, 0x34, 0xf6, 0xe8, 0xaf, 0xfb, 0x4d, 0x14, 0x98,
+                    0x0f, 0xf1, 0xbb, 0x93, 0xf2, 0x72,
+                ],
+            ),
+            (
+                39,
+                [
+                    0xef, 0x28, 0x52, 0xd0, 0xb8, 0x09, 0xb8, 0x3c, 0xf8, 0x6b, 0x32, 0xf2, 0x70,
+                    0x76, 0x37, 0x9b, 0x86, 0x8c, 0x97, 0xd0, 0x5a, 0xb1, 0x11, 0xd4, 0xae, 0x27,
+                    0xec, 0x60, 0x98, 0x8e, 0xdd, 0xba,
+                ],
+            ),
+        ] {
+            let mut rng = CtrDrbg::new(&ENTROPY_INPUT, &range::<39>(0x40)[..length]);
+            let mut buf = [0; 32];
+            rng.generate(&mut buf, &[]).unwrap();
+            assert_eq!(buf, expected, "personalization string of {} bytes", length);
+        }
+
+        let mut rng = CtrDrbg::from_seed(ENTROPY_INPUT.into());
+        let mut buf = [0; 32];
+        rng.generate(&mut buf, &range::<7>(0x60)).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x05, 0x1a, 0x0c, 0xcf, 0xea, 0x8a, 0xff, 0x2b, 0x9b, 0x21, 0x41, 0x58, 0x6a, 0x35,
+                0x0f, 0xbe, 0x32, 0x3c, 0x20, 0x6c, 0xe1, 0x4e, 0xdc, 0x96, 0x00, 0x8d, 0x2e, 0x30,
+                0x24, 0x7c, 0x13, 0x7d,
+            ]
+        );
+        rng.reseed(&range::<32>(0x80), &range::<23>(0xa0));
+        rng.generate(&mut buf, &[]).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x28, 0x93, 0xc9, 0x0f, 0x09, 0xcb, 0xa5, 0x9e, 0x0d, 0x7b, 0x0f, 0x4a, 0xef, 0xa4,
+                0xf5, 0x14, 0x1a, 0x94, 0x36, 0x16, 0x40, 0xde, 0x5b, 0x7d, 0xb7, 0xc7, 0xee, 0x27,
+                0xb5, 0x35, 0xb5, 0x6f,
+            ]
+        );
+    }
+
+    #[test]
+    fn aes128() {
+        let mut rng = CtrDrbg::with_security_strength(
+            &ENTROPY_INPUT[..24],
+            &range::<32>(0x40),
+            SecurityStrength::Bits128,
+        );
+        let mut buf = [0; 64];
+        rng.generate(&mut buf, &range::<32>(0x60)).unwrap();
+        rng.generate(&mut buf, &range::<32>(0xa0)).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0xb8, 0x51, 0xae, 0x56, 0xa1, 0x8a, 0xe1, 0x41, 0x4f, 0x55, 0x69, 0xb6, 0x62, 0x35,
+                0x59, 0x00, 0xbe, 0x77, 0xf4, 0x62, 0x77, 0x77, 0x0a, 0xb1, 0x5c, 0xd2, 0xb1, 0x97,
+                0xf4, 0xc1, 0xef, 0x59, 0x63, 0x11, 0x41, 0x1b, 0xee, 0x26, 0xe0, 0xd0, 0x0e, 0xc0,
+                0xaa, 0x68, 0x10, 0x36, 0x48, 0xa9, 0x34, 0x65, 0x76, 0x04, 0xe3, 0x13, 0x0a, 0x17,
+                0x10, 0xa7, 0x6a, 0xf5, 0x3c, 0x15, 0x12, 0x7b,
+            ]
+        );
+    }
+
+    #[test]
+    fn aes192() {
+        let mut rng = CtrDrbg::randombytes_init(&ENTROPY_INPUT[..36], &[], 192).unwrap();
+        assert_eq!(rng.security_strength(), SecurityStrength::Bits192);
+        let mut buf = [0; 64];
+        rng.fill_bytes(&mut buf);
+        rng.fill_bytes(&mut buf);
+        assert_eq!(
+            buf,
+            [
+                0x1a, 0xe8, 0x0a, 0x93, 0x63, 0x29, 0x93, 0x2a, 0x30, 0x4a, 0x94, 0x77, 0x75, 0x57,
+                0x5f, 0xe9, 0xf1, 0x1a, 0x4d, 0xa8, 0x55, 0xa1, 0x36, 0x57, 0x60, 0x2c, 0xe8, 0x4e,
+                0x57, 0x78, 0x05, 0x90, 0x91, 0x99, 0x14, 0x24, 0xd8, 0xe0, 0x59, 0xff, 0xa1, 0x8d,
+                0x88, 0xc0, 0x44, 0x32, 0xb0, 0x4e, 0xd9, 0xf7, 0xc2, 0x0f, 0x31, 0x33, 0x15, 0xf2,
+                0x50, 0x7f, 0x1d, 0xd7, 0xe4, 0xfb, 0x8f, 0x75,
+            ]
+        );
+    }
+
+    #[test]
+    fn unsupported_security_strength() {
+        for security_strength in [0, 64, 112, 512] {
+            assert_eq!(
+                CtrDrbg::randombytes_init(&ENTROPY_INPUT, &[], security_strength).unwrap_err(),
+                Error::UnsupportedSecurityStrength
+            );
+        }
+    }
+
+    #[test]
+    fn request_limits() {
+        let mut rng = CtrDrbg::from_seed(ENTROPY_INPUT.into());
+        let mut buf = [0; MAX_BYTES_PER_REQUEST + 1];
+        assert_eq!(rng.generate(&mut buf, &[]), Err(Error::RequestTooLarge));
+        assert!(rng.try_fill_bytes(&mut buf).is_ok());
+
+        rng.reseed_counter = RESEED_INTERVAL + 1;
+        assert_eq!(
+            rng.generate(&mut buf[..16], &[]),
+            Err(Error::ReseedRequired)
+        );
+        rng.reseed(&ENTROPY_INPUT, &[]);
+        assert!(rng.generate(&mut buf[..16], &[]).is_ok());
+    }
+
+    /// Entropy source returning consecutive bytes
+    struct CountingEntropy(u8);
+
+    impl EntropySource for CountingEntropy {
+        fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            dest.iter_mut().for_each(|byte| {
+                *byte = self.0;
+                self.0 = self.0.wrapping_add(1);
+            });
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn prediction_resistance() {
+        let mut rng = PredictionResistantCtrDrbg::new(CountingEntropy(0), &[]).unwrap();
+        assert!(rng.prediction_resistance());
+
+        let mut buf = [0; 32];
+        rng.generate(&mut buf, &range::<16>(0x80)).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x92, 0x37, 0x2d, 0x75, 0x73, 0x55, 0x0e, 0xba, 0x12, 0xae, 0xa5, 0xad, 0x57, 0xf2,
+                0xc0, 0xc4, 0xc0, 0x63, 0x7e, 0xb1, 0xd7, 0x75, 0x24, 0x06, 0x24, 0x46, 0xca, 0xd9,
+                0xc5, 0x3c, 0xbe, 0xb6,
+            ]
+        );
+        let mut buf = [0; 16];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(
+            buf,
+            [
+                0x65, 0xc4, 0x5a, 0x18, 0x8a, 0x84, 0xe1, 0xb2, 0x93, 0xa1, 0xaf, 0x6a, 0xbb, 0xfe,
+                0x3a, 0x92,
+            ]
+        );
+        assert_eq!(rng.into_entropy_source().0, 112);
+    }
+
+    #[test]
+    fn without_prediction_resistance() {
+        let mut rng = PredictionResistantCtrDrbg::new(CountingEntropy(0), &[]).unwrap();
+        rng.set_prediction_resistance(false);
+
+        let mut buf = [0; 32];
+        rng.generate(&mut buf, &range::<16>(0x80)).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x64, 0x32, 0x1d, 0x6e, 0x13, 0x67, 0x43, 0x94, 0x8c, 0x32, 0xd5, 0xb7, 0xc2, 0xcf,
+                0xf3, 0x18, 0x06, 0x01, 0xde, 0x1c, 0x03, 0x0b, 0xaa, 0x4a, 0x62, 0x31, 0x86, 0x91,
+                0x50, 0x7d, 0x22, 0x03,
+            ]
+        );
+        let mut buf = [0; 16];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(
+            buf,
+            [
+                0x78, 0x47, 0x9c, 0x24, 0xc4, 0x7d, 0x58, 0x0c, 0x68, 0xf9, 0xb7, 0x2e, 0x4f, 0x3b,
+                0x9a, 0xd9,
+            ]
+        );
+        assert_eq!(rng.into_entropy_source().0, 48);
+    }
+
+    #[test]
+    fn builder() {
+        let mut rng = CtrDrbg::builder(&ENTROPY_INPUT[..32])
+            .nonce(&ENTROPY_INPUT[32..])
+            .personalization_string(&range::<32>(0x40))
+            .build();
+        let mut buf = [0; 32];
+        rng.generate(&mut buf, &[]).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0xde, 0xfc, 0x57, 0xca, 0xb8, 0x40, 0xdb, 0x9d, 0x3b, 0xad, 0xca, 0x6e, 0xb6, 0xf5,
+                0x25, 0xee, 0x87, 0xa9, 0x29, 0x0a, 0x43, 0xd9, 0xc8, 0xa7, 0xb0, 0x17, 0x9d, 0xdd,
+                0x6e, 0xd3, 0xfa, 0xec,
+            ]
+        );
+
+        let mut rng = CtrDrbgBuilder::new(&ENTROPY_INPUT[..16])
+            .nonce(&ENTROPY_INPUT[16..24])
+            .personalization_string(&range::<16>(0x40))
+            .security_strength(SecurityStrength::Bits128)
+            .build();
+        let mut buf = [0; 16];
+        rng.generate(&mut buf, &[]).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0xa4, 0x99, 0x3d, 0x76, 0x55, 0xa5, 0x1e, 0x8e, 0xb4, 0x98, 0x80, 0xa3, 0x4d, 0x2b,
+                0x88, 0x70,
+            ]
+        );
+    }
+
+    #[test]
+    fn builder_without_nonce() {
+        let mut rng_1 = CtrDrbg::builder(&ENTROPY_INPUT).build();
+        let mut rng_2 = CtrDrbg::new(&ENTROPY_INPUT, &[]);
+        assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+    }
+
+    #[test]
+    fn derivation_function() {
+        let personalization_string = range::<32>(0x40);
+        let mut seed_material = [0; SEED_LENGTH];
+        let mut input = [0; SEED_LENGTH + 32];
+        input[..SEED_LENGTH].copy_from_slice(&ENTROPY_INPUT);
+        input[SEED_LENGTH..].copy_from_slice(&personalization_string);
+        block_cipher_df(SecurityStrength::Bits256, &input, &mut seed_material).unwrap();
+
+        // instantiating without derivation function from the derived seed
+        // material yields the same DRBG
+        let mut rng_1 = crate::NistPqcAes256CtrRng::from(seed_material);
+        let mut rng_2 = CtrDrbg::new(&ENTROPY_INPUT, &personalization_string);
+        assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+
+        let mut output = [0; MAX_DF_OUTPUT_LENGTH + 1];
+        assert!(block_cipher_df(
+            SecurityStrength::Bits128,
+            &input,
+            &mut output[..MAX_DF_OUTPUT_LENGTH]
+        )
+        .is_ok());
+        assert_eq!(
+            block_cipher_df(SecurityStrength::Bits128, &input, &mut output),
+            Err(Error::RequestTooLarge)
+        );
+    }
+
+    /// The chaining values of BCC, X and the temporary key and X are zeroized
+    #[cfg(all(feature = "zeroize", feature = "std"))]
+    #[test]
+    fn derivation_function_zeroizes_buffers() {
+        crate::guard::take_zeroized();
+        let mut output = [0; SEED_LENGTH];
+        block_cipher_df(SecurityStrength::Bits256, &ENTROPY_INPUT, &mut output).unwrap();
+        assert_eq!(
+            crate::guard::take_zeroized(),
+            [V_LENGTH, V_LENGTH, V_LENGTH, V_LENGTH, SEED_LENGTH]
+        );
+    }
+
+    #[test]
+    fn update_function() {
+        let mut key = [0; 32];
+        let mut v = [0; V_LENGTH];
+        ctr_drbg_update(&mut key, &mut v, &[0; SEED_LENGTH]).unwrap();
+        assert_eq!(
+            key,
+            [
+                0x53, 0x0f, 0x8a, 0xfb, 0xc7, 0x45, 0x36, 0xb9, 0xa9, 0x63, 0xb4, 0xf1, 0xc4, 0xcb,
+                0x73, 0x8b, 0xce, 0xa7, 0x40, 0x3d, 0x4d, 0x60, 0x6b, 0x6e, 0x07, 0x4e, 0xc5, 0xd3,
+                0xba, 0xf3, 0x9d, 0x18,
+            ]
+        );
+
+        let mut key = [0; 20];
+        assert_eq!(
+            ctr_drbg_update(&mut key, &mut v, &[0; 36]),
+            Err(Error::UnsupportedSecurityStrength)
+        );
+    }
+}
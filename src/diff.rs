@@ -0,0 +1,168 @@
+//! RNG wrapper comparing two RNGs in lockstep.
+
+use alloc::vec;
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{expect::Divergence, Error};
+
+/// RNG forwarding every request to two RNGs and comparing their outputs
+///
+/// This enables differential testing between two implementations, e.g., this
+/// crate and bindings to `rng.c`:
+/// ```
+/// use nist_pqc_seeded_rng::{
+///     diff::DiffRng, NistPqcAes256CtrRng, RngCore, Seed, SeedableRng,
+/// };
+///
+/// let mut rng = DiffRng::new(
+///     NistPqcAes256CtrRng::from_seed(Seed::default()),
+///     NistPqcAes256CtrRng::from_seed(Seed::default()),
+/// );
+/// rng.fill_bytes(&mut [0; 32]);
+/// rng.next_u64();
+/// assert!(rng.divergence().is_none());
+/// ```
+///
+/// The output is the output of the first RNG. If the outputs differ,
+/// [RngCore::try_fill_bytes] fails with [Error::OutputMismatch] and the
+/// infallible methods panic. The offset of the first divergence counts the
+/// bytes of all requests and is available from
+/// [divergence](Self::divergence).
+#[derive(Clone, Debug)]
+pub struct DiffRng<A: RngCore, B: RngCore> {
+    a: A,
+    b: B,
+    offset: usize,
+    calls: usize,
+    divergence: Option<Divergence>,
+}
+
+impl<A: RngCore, B: RngCore> DiffRng<A, B> {
+    /// Compare the outputs of `a` and `b`.
+    pub const fn new(a: A, b: B) -> Self {
+        Self {
+            a,
+            b,
+            offset: 0,
+            calls: 0,
+            divergence: None,
+        }
+    }
+
+    /// The first divergence of the outputs, if any.
+    pub const fn divergence(&self) -> Option<Divergence> {
+        self.divergence
+    }
+
+    /// Return the wrapped RNGs.
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+
+    /// Compare the outputs of the current request.
+    fn compare(&mut self, a: &[u8], b: &[u8]) -> Result<(), Divergence> {
+        let call = self.calls;
+        self.calls += 1;
+        let index = a.iter().zip(b).position(|(a, b)| a != b);
+        let offset = self.offset;
+        self.offset += a.len();
+        match index {
+            Some(index) => {
+                let divergence = Divergence {
+                    offset: offset + index,
+                    call,
+                };
+                self.divergence.get_or_insert(divergence);
+                Err(divergence)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+fn report(divergence: Divergence) -> ! {
+    panic!(
+        "outputs of the RNGs differ at offset {} in call {}",
+        divergence.offset, divergence.call
+    )
+}
+
+impl<A: RngCore, B: RngCore> RngCore for DiffRng<A, B> {
+    fn next_u32(&mut self) -> u32 {
+        let a = self.a.next_u32();
+        let b = self.b.next_u32();
+        if let Err(divergence) = self.compare(&a.to_le_bytes(), &b.to_le_bytes()) {
+            report(divergence);
+        }
+        a
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let a = self.a.next_u64();
+        let b = self.b.next_u64();
+        if let Err(divergence) = self.compare(&a.to_le_bytes(), &b.to_le_bytes()) {
+            report(divergence);
+        }
+        a
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut b = vec![0; dest.len()];
+        self.a.fill_bytes(dest);
+        self.b.fill_bytes(&mut b);
+        if let Err(divergence) = self.compare(dest, &b) {
+            report(divergence);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        let mut b = vec![0; dest.len()];
+        let result_a = self.a.try_fill_bytes(dest);
+        self.b.try_fill_bytes(&mut b)?;
+        result_a?;
+        self.compare(dest, &b)
+            .map_err(|_| Error::OutputMismatch.into())
+    }
+}
+
+impl<A: RngCore + CryptoRng, B: RngCore + CryptoRng> CryptoRng for DiffRng<A, B> {}
+
+#[cfg(test)]
+mod test {
+    use rand_core::SeedableRng;
+
+    use super::*;
+    use crate::{buffered::WordBufferedRng, NistPqcAes256CtrRng, Seed};
+
+    #[test]
+    fn diverging() {
+        let mut rng = DiffRng::new(
+            NistPqcAes256CtrRng::from_seed(Seed::default()),
+            WordBufferedRng::new(NistPqcAes256CtrRng::from_seed(Seed::default())),
+        );
+        // `fill_bytes` is forwarded and the first word is the start of a request
+        assert!(rng.try_fill_bytes(&mut [0; 10]).is_ok());
+        assert!(rng.try_fill_bytes(&mut [0; 3]).is_ok());
+        rng.next_u32();
+        assert!(rng.divergence().is_none());
+        assert!(rng.try_fill_bytes(&mut [0; 4]).is_err());
+        assert_eq!(
+            rng.divergence(),
+            Some(Divergence {
+                offset: 17,
+                call: 3
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "outputs of the RNGs differ at offset 0 in call 0")]
+    fn panics() {
+        let mut rng = DiffRng::new(
+            NistPqcAes256CtrRng::from_seed(Seed::default()),
+            NistPqcAes256CtrRng::from_seed(Seed::from([1; 48])),
+        );
+        rng.fill_bytes(&mut [0; 4]);
+    }
+}
@@ -0,0 +1,102 @@
+//! Building blocks of CTR_DRBG shared by the DRBG variants.
+
+use aes::cipher::{
+    consts::U16, generic_array::GenericArray, BlockCipher, BlockEncrypt, KeyInit, KeyIvInit,
+    StreamCipher, StreamCipherSeek,
+};
+
+use crate::{guard::ZeroizeGuard, SEED_LENGTH, V_LENGTH};
+
+/// Block ciphers usable with the DRBG.
+pub(crate) trait Cipher: BlockCipher<BlockSize = U16> + BlockEncrypt + KeyInit {}
+
+impl<C> Cipher for C where C: BlockCipher<BlockSize = U16> + BlockEncrypt + KeyInit {}
+
+/// `CTR_DRBG_Update` using the current position of the keystream.
+fn update_with<C: Cipher>(
+    mut cipher: ctr::Ctr128BE<C>,
+    key: &mut [u8],
+    v: &mut [u8; V_LENGTH],
+    provided_data: &[u8],
+) {
+    let mut temp = [0; SEED_LENGTH];
+    let mut temp = ZeroizeGuard::new(&mut temp[..provided_data.len()]);
+    temp.copy_from_slice(provided_data);
+    cipher.apply_keystream(&mut temp);
+    key.copy_from_slice(&temp[..key.len()]);
+    v.copy_from_slice(&temp[key.len()..]);
+}
+
+/// `CTR_DRBG_Update` starting from the current key and V.
+pub(crate) fn update<C: Cipher>(key: &mut [u8], v: &mut [u8; V_LENGTH], provided_data: &[u8]) {
+    let mut cipher =
+        ctr::Ctr128BE::<C>::new(GenericArray::from_slice(key), GenericArray::from_slice(v));
+    cipher.seek(V_LENGTH);
+    update_with(cipher, key, v, provided_data);
+}
+
+/// Generate output followed by `CTR_DRBG_Update` with the additional input.
+pub(crate) fn generate<C: Cipher>(
+    key: &mut [u8],
+    v: &mut [u8; V_LENGTH],
+    dest: &mut [u8],
+    additional_input: &[u8],
+) {
+    generate_skipping::<C>(key, v, dest, additional_input, 0);
+}
+
+/// Generate output, skip `skip_blocks` blocks of the keystream and perform
+/// `CTR_DRBG_Update` with the additional input.
+pub(crate) fn generate_skipping<C: Cipher>(
+    key: &mut [u8],
+    v: &mut [u8; V_LENGTH],
+    dest: &mut [u8],
+    additional_input: &[u8],
+    skip_blocks: usize,
+) {
+    generate_with::<C>(key, v, additional_input, skip_blocks, |cipher| {
+        dest.fill(0);
+        cipher.apply_keystream(dest);
+    });
+}
+
+/// Generate output without updating the key and V.
+pub(crate) fn peek<C: Cipher>(key: &[u8], v: &[u8; V_LENGTH], dest: &mut [u8]) {
+    let mut cipher =
+        ctr::Ctr128BE::<C>::new(GenericArray::from_slice(key), GenericArray::from_slice(v));
+    cipher.seek(V_LENGTH);
+    dest.fill(0);
+    cipher.apply_keystream(dest);
+}
+
+/// Discard `length` bytes of output, skip `skip_blocks` blocks of the
+/// keystream and perform `CTR_DRBG_Update` with the additional input.
+pub(crate) fn discard_skipping<C: Cipher>(
+    key: &mut [u8],
+    v: &mut [u8; V_LENGTH],
+    length: usize,
+    additional_input: &[u8],
+    skip_blocks: usize,
+) {
+    generate_with::<C>(key, v, additional_input, skip_blocks, |cipher| {
+        cipher.seek(V_LENGTH + length);
+    });
+}
+
+/// Generate output by consuming the keystream with `output`, then skip the
+/// remainder of the current block and `skip_blocks` further blocks and perform
+/// `CTR_DRBG_Update` with the additional input.
+pub(crate) fn generate_with<C: Cipher>(
+    key: &mut [u8],
+    v: &mut [u8; V_LENGTH],
+    additional_input: &[u8],
+    skip_blocks: usize,
+    output: impl FnOnce(&mut ctr::Ctr128BE<C>),
+) {
+    let mut cipher =
+        ctr::Ctr128BE::<C>::new(GenericArray::from_slice(key), GenericArray::from_slice(v));
+    cipher.seek(V_LENGTH);
+    output(&mut cipher);
+    cipher.seek((cipher.current_pos::<usize>().div_ceil(V_LENGTH) + skip_blocks) * V_LENGTH);
+    update_with(cipher, key, v, additional_input);
+}
@@ -0,0 +1,284 @@
+//! Object-safe interface to the DRBGs to select the DRBG at runtime.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+#[cfg(feature = "alloc")]
+use aes::{Aes128, Aes192, Aes256};
+#[cfg(feature = "alloc")]
+use rand_core::SeedableRng;
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "alloc")]
+use crate::SecurityStrength;
+use crate::{ctr_drbg, CtrDrbg, CtrDrbgCipher, Error, SeededCtrDrbg, V_LENGTH};
+
+/// Object-safe interface implemented by the DRBGs
+///
+/// In contrast to [RngCore], requests of arbitrary length are split into
+/// requests of at most [MAX_BYTES_PER_REQUEST](ctr_drbg::MAX_BYTES_PER_REQUEST)
+/// bytes by the DRBGs implementing the limits of NIST SP 800-90A. For
+/// [SeededCtrDrbg], this is the case in strict mode only.
+pub trait DynDrbg {
+    /// Fill `dest` with random bytes.
+    fn fill(&mut self, dest: &mut [u8]) -> Result<(), Error>;
+
+    /// Reseed the DRBG with a fresh entropy input and additional input.
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) -> Result<(), Error>;
+
+    /// Length of the seed in bytes.
+    fn seed_len(&self) -> usize;
+}
+
+impl<C: CtrDrbgCipher> DynDrbg for SeededCtrDrbg<C> {
+    /// Fill `dest` with random bytes.
+    ///
+    /// In strict mode, the request is split into requests of at most
+    /// [MAX_BYTES_PER_REQUEST](ctr_drbg::MAX_BYTES_PER_REQUEST) bytes.
+    /// Otherwise, it is processed as a single request like `rng.c` does.
+    fn fill(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        if !self.strict {
+            return self.try_generate(dest, &[]);
+        }
+        for chunk in dest.chunks_mut(ctr_drbg::MAX_BYTES_PER_REQUEST) {
+            self.try_generate(chunk, &[])?;
+        }
+        Ok(())
+    }
+
+    /// Reseed the RNG.
+    ///
+    /// Fails if the entropy input is not exactly as long as the seed or if
+    /// the additional input is longer than the seed.
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) -> Result<(), Error> {
+        let seed_length = self.seed_len();
+        if entropy_input.len() != seed_length || additional_input.len() > seed_length {
+            return Err(Error::InvalidInputLength);
+        }
+
+        let mut seed = C::Seed::default();
+        seed.as_mut().copy_from_slice(entropy_input);
+        SeededCtrDrbg::reseed(self, &seed, Some(additional_input));
+        Ok(())
+    }
+
+    fn seed_len(&self) -> usize {
+        C::key_size() + V_LENGTH
+    }
+}
+
+impl DynDrbg for CtrDrbg {
+    fn fill(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        for chunk in dest.chunks_mut(ctr_drbg::MAX_BYTES_PER_REQUEST) {
+            self.generate(chunk, &[])?;
+        }
+        Ok(())
+    }
+
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) -> Result<(), Error> {
+        CtrDrbg::reseed(self, entropy_input, additional_input);
+        Ok(())
+    }
+
+    fn seed_len(&self) -> usize {
+        self.security_strength().seed_length()
+    }
+}
+
+#[cfg(feature = "hmac-drbg")]
+impl<D> DynDrbg for crate::HmacDrbg<D>
+where
+    D: hmac::digest::Digest + hmac::digest::core_api::BlockSizeUser,
+{
+    fn fill(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        for chunk in dest.chunks_mut(crate::hmac_drbg::MAX_BYTES_PER_REQUEST) {
+            self.generate(chunk, &[])?;
+        }
+        Ok(())
+    }
+
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) -> Result<(), Error> {
+        crate::HmacDrbg::reseed(self, entropy_input, additional_input);
+        Ok(())
+    }
+
+    /// The length of [Seed](crate::Seed) for all hash functions.
+    fn seed_len(&self) -> usize {
+        <Self as crate::DrbgParameters>::SEED_LEN
+    }
+}
+
+#[cfg(feature = "hash-drbg")]
+impl DynDrbg for crate::HashDrbgSha256 {
+    fn fill(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        for chunk in dest.chunks_mut(crate::hash_drbg::MAX_BYTES_PER_REQUEST) {
+            self.generate(chunk, &[])?;
+        }
+        Ok(())
+    }
+
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) -> Result<(), Error> {
+        crate::HashDrbgSha256::reseed(self, entropy_input, additional_input);
+        Ok(())
+    }
+
+    fn seed_len(&self) -> usize {
+        crate::SEED_LENGTH
+    }
+}
+
+impl RngCore for dyn DynDrbg + '_ {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.fill(dest).expect("DRBG failed")
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill(dest).map_err(Into::into)
+    }
+}
+
+impl CryptoRng for dyn DynDrbg + '_ {}
+
+/// DRBG variants that can be instantiated at runtime
+///
+/// With the `serde` feature, the variant can be read from a configuration
+/// file.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DrbgKind {
+    /// [NistPqcAes256CtrRng](crate::NistPqcAes256CtrRng)
+    NistPqcAes256Ctr,
+    /// [NistAes128CtrRng](crate::NistAes128CtrRng)
+    NistAes128Ctr,
+    /// [NistAes192CtrRng](crate::NistAes192CtrRng)
+    NistAes192Ctr,
+    /// [CtrDrbg] with the given security strength
+    CtrDrbg(SecurityStrength),
+    /// [HmacDrbgSha256](crate::HmacDrbgSha256)
+    #[cfg(feature = "hmac-drbg")]
+    HmacDrbgSha256,
+    /// [HmacDrbgSha512](crate::HmacDrbgSha512)
+    #[cfg(feature = "hmac-drbg")]
+    HmacDrbgSha512,
+    /// [HashDrbgSha256](crate::HashDrbgSha256)
+    #[cfg(feature = "hash-drbg")]
+    HashDrbgSha256,
+}
+
+#[cfg(feature = "alloc")]
+impl DrbgKind {
+    /// Instantiate the DRBG from an entropy input.
+    ///
+    /// The RNGs from `rng.c` require an entropy input of exactly the seed
+    /// length and fail with [Error::InvalidInputLength] otherwise. The other
+    /// DRBGs are instantiated with an empty personalization string.
+    pub fn instantiate(self, entropy_input: &[u8]) -> Result<Box<dyn DynDrbg>, Error> {
+        fn seeded<C: CtrDrbgCipher + 'static>(
+            entropy_input: &[u8],
+        ) -> Result<Box<dyn DynDrbg>, Error> {
+            let mut seed = C::Seed::default();
+            if seed.as_ref().len() != entropy_input.len() {
+                return Err(Error::InvalidInputLength);
+            }
+            seed.as_mut().copy_from_slice(entropy_input);
+            Ok(Box::new(SeededCtrDrbg::<C>::from_seed(seed)))
+        }
+
+        match self {
+            Self::NistPqcAes256Ctr => seeded::<Aes256>(entropy_input),
+            Self::NistAes128Ctr => seeded::<Aes128>(entropy_input),
+            Self::NistAes192Ctr => seeded::<Aes192>(entropy_input),
+            Self::CtrDrbg(security_strength) => Ok(Box::new(CtrDrbg::with_security_strength(
+                entropy_input,
+                &[],
+                security_strength,
+            ))),
+            #[cfg(feature = "hmac-drbg")]
+            Self::HmacDrbgSha256 => Ok(Box::new(crate::HmacDrbgSha256::new(entropy_input, &[]))),
+            #[cfg(feature = "hmac-drbg")]
+            Self::HmacDrbgSha512 => Ok(Box::new(crate::HmacDrbgSha512::new(entropy_input, &[]))),
+            #[cfg(feature = "hash-drbg")]
+            Self::HashDrbgSha256 => Ok(Box::new(crate::HashDrbgSha256::new(entropy_input, &[]))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{NistPqcAes256CtrRng, Seed};
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn seeded_ctr_drbg() {
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let rng: &mut dyn DynDrbg = &mut rng_1;
+        assert_eq!(rng.seed_len(), 48);
+        assert_eq!(rng.next_u64(), rng_2.next_u64());
+
+        assert_eq!(rng.reseed(&[0; 32], &[]), Err(Error::InvalidInputLength));
+        assert_eq!(
+            rng.reseed(&[0; 48], &[0; 49]),
+            Err(Error::InvalidInputLength)
+        );
+        rng.reseed(&[1; 48], &[2; 16]).unwrap();
+        rng_2.reseed(&Seed::from([1; 48]), Some(&[2; 16]));
+        assert_eq!(rng.next_u64(), rng_2.next_u64());
+    }
+
+    #[test]
+    fn seeded_ctr_drbg_large_requests() {
+        let mut buf_1 = [0; ctr_drbg::MAX_BYTES_PER_REQUEST + 16];
+        let mut buf_2 = [0; ctr_drbg::MAX_BYTES_PER_REQUEST + 16];
+
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        DynDrbg::fill(&mut rng_1, &mut buf_1).unwrap();
+        rng_2.fill_bytes(&mut buf_2);
+        assert_eq!(buf_1, buf_2);
+        assert_eq!(rng_1.reseed_counter(), 2);
+
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed_strict(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        DynDrbg::fill(&mut rng_1, &mut buf_1).unwrap();
+        let (first, second) = buf_2.split_at_mut(ctr_drbg::MAX_BYTES_PER_REQUEST);
+        rng_2.fill_bytes(first);
+        rng_2.fill_bytes(second);
+        assert_eq!(buf_1, buf_2);
+        assert_eq!(rng_1.reseed_counter(), 3);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn instantiate() {
+        let mut rng_1 = DrbgKind::NistPqcAes256Ctr.instantiate(&[0; 48]).unwrap();
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+        assert!(DrbgKind::NistAes128Ctr.instantiate(&[0; 48]).is_err());
+
+        let mut rng_1 = DrbgKind::CtrDrbg(SecurityStrength::Bits128)
+            .instantiate(&[0; 24])
+            .unwrap();
+        let mut rng_2 = CtrDrbg::with_security_strength(&[0; 24], &[], SecurityStrength::Bits128);
+        assert_eq!(rng_1.seed_len(), 32);
+        let mut buf_1 = [0; ctr_drbg::MAX_BYTES_PER_REQUEST + 16];
+        let mut buf_2 = [0; ctr_drbg::MAX_BYTES_PER_REQUEST + 16];
+        rng_1.fill(&mut buf_1).unwrap();
+        rng_2.fill_bytes(&mut buf_2);
+        assert_eq!(buf_1, buf_2);
+    }
+}
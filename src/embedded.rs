@@ -0,0 +1,69 @@
+//! Global RNG for bare-metal targets.
+//!
+//! The RNG mimics the global state of `rng.c` without `std`. It is protected
+//! by a [critical section](critical_section), so an implementation of the
+//! critical section has to be provided for the target:
+//! ```
+//! use nist_pqc_seeded_rng::{embedded, Seed};
+//!
+//! embedded::init(Seed::default());
+//! let mut coins = [0; 32];
+//! embedded::fill(&mut coins);
+//! ```
+
+use core::cell::RefCell;
+
+use critical_section::Mutex;
+use rand_core::{RngCore, SeedableRng};
+
+use crate::{NistPqcAes256CtrRng, Seed};
+
+static RNG: Mutex<RefCell<Option<NistPqcAes256CtrRng>>> = Mutex::new(RefCell::new(None));
+
+/// Instantiate the global RNG from `seed`.
+///
+/// This is equivalent to `randombytes_init(seed, NULL, 256)` of `rng.c`. A
+/// previously instantiated RNG is replaced.
+pub fn init(seed: Seed) {
+    critical_section::with(|cs| {
+        RNG.borrow_ref_mut(cs)
+            .replace(NistPqcAes256CtrRng::from_seed(seed));
+    });
+}
+
+/// Fill `dest` with output of the global RNG.
+///
+/// This is equivalent to `randombytes(dest, dest.len())` of `rng.c`.
+///
+/// # Panics
+///
+/// Panics if the global RNG has not been instantiated with [init].
+pub fn fill(dest: &mut [u8]) {
+    critical_section::with(|cs| {
+        RNG.borrow_ref_mut(cs)
+            .as_mut()
+            .expect("global RNG not instantiated")
+            .fill_bytes(dest);
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn global() {
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut expected = [0; 32];
+        reference.fill_bytes(&mut expected);
+
+        init(Seed::default());
+        let mut buf = [0; 32];
+        fill(&mut buf);
+        assert_eq!(buf, expected);
+
+        init(Seed::default());
+        fill(&mut buf);
+        assert_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,42 @@
+//! Sources of entropy inputs for the DRBGs.
+
+use crate::Error;
+
+/// Source of entropy inputs used to instantiate and reseed a DRBG
+///
+/// Implementations for tests can return fixed or recorded entropy inputs to
+/// obtain reproducible outputs.
+pub trait EntropySource {
+    /// Fill `dest` with entropy.
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), Error>;
+}
+
+impl<E: EntropySource + ?Sized> EntropySource for &mut E {
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        (**self).fill_entropy(dest)
+    }
+}
+
+/// Entropy source backed by the operating system's RNG via [getrandom]
+#[cfg(feature = "getrandom")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsEntropy;
+
+#[cfg(feature = "getrandom")]
+impl EntropySource for OsEntropy {
+    fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        getrandom::getrandom(dest).map_err(|_| Error::EntropySourceFailed)
+    }
+}
+
+#[cfg(all(test, feature = "getrandom"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn os_entropy() {
+        let mut buf = [0; 32];
+        OsEntropy.fill_entropy(&mut buf).unwrap();
+        assert_ne!(buf, [0; 32]);
+    }
+}
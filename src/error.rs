@@ -0,0 +1,166 @@
+//! Error types shared by the DRBGs.
+
+use core::fmt;
+
+/// Errors reported by the DRBGs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The reseed interval has been exceeded and the DRBG needs to be reseeded.
+    ReseedRequired,
+    /// More bytes than allowed in a single request have been requested.
+    RequestTooLarge,
+    /// The requested security strength is not supported.
+    UnsupportedSecurityStrength,
+    /// The request exceeds the remaining output length of the seed expander or
+    /// of a [ReplayRng](crate::replay::ReplayRng).
+    ///
+    /// This error corresponds to `RNG_BAD_REQ_LEN` in `rng.c`.
+    MaxLengthExceeded,
+    /// The entropy source failed to provide entropy.
+    EntropySourceFailed,
+    /// An input is of invalid length.
+    InvalidInputLength,
+    /// The request exceeds the remaining budget of a
+    /// [BudgetRng](crate::budget::BudgetRng).
+    BudgetExceeded,
+    /// The output differs from the expected output.
+    OutputMismatch,
+    /// The request differs from the expected pattern of requests.
+    UnexpectedRequest,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ReseedRequired => write!(f, "reseed required"),
+            Self::RequestTooLarge => write!(f, "request too large"),
+            Self::UnsupportedSecurityStrength => write!(f, "unsupported security strength"),
+            Self::MaxLengthExceeded => write!(f, "maximal output length exceeded"),
+            Self::EntropySourceFailed => write!(f, "entropy source failed"),
+            Self::InvalidInputLength => write!(f, "input of invalid length"),
+            Self::BudgetExceeded => write!(f, "randomness budget exceeded"),
+            Self::OutputMismatch => write!(f, "output mismatch"),
+            Self::UnexpectedRequest => write!(f, "unexpected request"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Error returned when converting a slice of invalid length into a seed or an
+/// RNG.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidSeedLength {
+    /// Expected length of the seed in bytes.
+    pub expected: usize,
+    /// Actual length of the slice in bytes.
+    pub actual: usize,
+}
+
+impl fmt::Display for InvalidSeedLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "seed of invalid length: expected {} bytes, got {} bytes",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl core::error::Error for InvalidSeedLength {}
+
+impl From<InvalidSeedLength> for Error {
+    fn from(_: InvalidSeedLength) -> Self {
+        Self::InvalidInputLength
+    }
+}
+
+/// Error returned when parsing a seed from a hex or base64 string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseSeedError {
+    /// The string is too short or too long to encode a seed.
+    InvalidLength {
+        /// Expected length of the string in bytes.
+        expected: usize,
+        /// Actual length of the string in bytes.
+        actual: usize,
+    },
+    /// The string contains a character that is not a hex digit.
+    InvalidCharacter {
+        /// Byte offset of the character in the string.
+        index: usize,
+    },
+    /// The string is not valid base64.
+    InvalidBase64,
+}
+
+impl fmt::Display for ParseSeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { expected, actual } => write!(
+                f,
+                "string of invalid length: expected {} characters, got {}",
+                expected, actual
+            ),
+            Self::InvalidCharacter { index } => {
+                write!(f, "invalid hex character at index {}", index)
+            }
+            Self::InvalidBase64 => write!(f, "invalid base64 encoding"),
+        }
+    }
+}
+
+impl core::error::Error for ParseSeedError {}
+
+impl From<Error> for rand_core::Error {
+    fn from(value: Error) -> Self {
+        let code = match value {
+            Error::ReseedRequired => rand_core::Error::CUSTOM_START,
+            Error::RequestTooLarge => rand_core::Error::CUSTOM_START + 1,
+            Error::UnsupportedSecurityStrength => rand_core::Error::CUSTOM_START + 2,
+            Error::MaxLengthExceeded => rand_core::Error::CUSTOM_START + 3,
+            Error::EntropySourceFailed => rand_core::Error::CUSTOM_START + 4,
+            Error::InvalidInputLength => rand_core::Error::CUSTOM_START + 5,
+            Error::BudgetExceeded => rand_core::Error::CUSTOM_START + 6,
+            Error::OutputMismatch => rand_core::Error::CUSTOM_START + 7,
+            Error::UnexpectedRequest => rand_core::Error::CUSTOM_START + 8,
+        };
+        core::num::NonZeroU32::new(code)
+            .expect("custom error codes are non-zero")
+            .into()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use std::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn invalid_seed_length() {
+        let err = InvalidSeedLength {
+            expected: 48,
+            actual: 32,
+        };
+        assert_eq!(
+            err.to_string(),
+            "seed of invalid length: expected 48 bytes, got 32 bytes"
+        );
+        assert_eq!(Error::from(err), Error::InvalidInputLength);
+    }
+
+    #[test]
+    fn parse_seed_error() {
+        let err = ParseSeedError::InvalidLength {
+            expected: 96,
+            actual: 95,
+        };
+        assert_eq!(
+            err.to_string(),
+            "string of invalid length: expected 96 characters, got 95"
+        );
+        let err = ParseSeedError::InvalidCharacter { index: 3 };
+        assert_eq!(err.to_string(), "invalid hex character at index 3");
+    }
+}
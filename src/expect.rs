@@ -0,0 +1,203 @@
+//! RNG wrapper comparing the output against a golden transcript.
+
+use core::fmt;
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::Error;
+
+/// Position of the first byte differing from the transcript
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Divergence {
+    /// Offset of the byte in the transcript.
+    pub offset: usize,
+    /// Index of the request containing the byte, starting from 0.
+    pub call: usize,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "output diverges from the transcript at offset {} in call {}",
+            self.offset, self.call
+        )
+    }
+}
+
+/// RNG verifying the output of the wrapped RNG against an expected transcript
+///
+/// Every request draws the bytes from the wrapped RNG and compares them with
+/// the next bytes of the transcript, e.g., the coins dumped by an
+/// instrumented C KAT harness:
+/// ```should_panic
+/// use nist_pqc_seeded_rng::{
+///     expect::ExpectRng, NistPqcAes256CtrRng, RngCore, Seed, SeedableRng,
+/// };
+///
+/// let transcript = [0x91, 0x61, 0x8f, 0xe9, 0x00];
+/// let mut rng = ExpectRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()), &transcript);
+/// rng.next_u32();
+/// // panics: output diverges from the transcript at offset 4 in call 1
+/// rng.next_u32();
+/// ```
+///
+/// On divergence, [RngCore::try_fill_bytes] fails with
+/// [Error::OutputMismatch] and the infallible methods panic. The first
+/// divergence is available from [divergence](Self::divergence). Bytes beyond
+/// the end of the transcript are treated as divergence.
+#[derive(Clone, Debug)]
+pub struct ExpectRng<'a, R: RngCore> {
+    rng: R,
+    transcript: &'a [u8],
+    offset: usize,
+    calls: usize,
+    divergence: Option<Divergence>,
+}
+
+impl<'a, R: RngCore> ExpectRng<'a, R> {
+    /// Verify the output of `rng` against `transcript`.
+    pub const fn new(rng: R, transcript: &'a [u8]) -> Self {
+        Self {
+            rng,
+            transcript,
+            offset: 0,
+            calls: 0,
+            divergence: None,
+        }
+    }
+
+    /// Number of bytes of the transcript verified so far.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Check whether the whole transcript has been verified.
+    pub const fn is_complete(&self) -> bool {
+        self.offset == self.transcript.len()
+    }
+
+    /// The first divergence from the transcript, if any.
+    pub const fn divergence(&self) -> Option<Divergence> {
+        self.divergence
+    }
+
+    /// Return the wrapped RNG.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+
+    /// Compare the output of the current request with the transcript.
+    fn verify(&mut self, output: &[u8]) -> Result<(), Divergence> {
+        let call = self.calls;
+        self.calls += 1;
+        let expected = &self.transcript[self.offset.min(self.transcript.len())..];
+        let index = output
+            .iter()
+            .zip(expected)
+            .position(|(byte, expected)| byte != expected)
+            .or_else(|| (output.len() > expected.len()).then_some(expected.len()));
+        match index {
+            Some(index) => {
+                let divergence = Divergence {
+                    offset: self.offset + index,
+                    call,
+                };
+                self.divergence.get_or_insert(divergence);
+                self.offset += output.len();
+                Err(divergence)
+            }
+            None => {
+                self.offset += output.len();
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<R: RngCore> RngCore for ExpectRng<'_, R> {
+    fn next_u32(&mut self) -> u32 {
+        let value = self.rng.next_u32();
+        if let Err(divergence) = self.verify(&value.to_le_bytes()) {
+            panic!("{}", divergence);
+        }
+        value
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let value = self.rng.next_u64();
+        if let Err(divergence) = self.verify(&value.to_le_bytes()) {
+            panic!("{}", divergence);
+        }
+        value
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+        if let Err(divergence) = self.verify(dest) {
+            panic!("{}", divergence);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.rng.try_fill_bytes(dest)?;
+        self.verify(dest).map_err(|_| Error::OutputMismatch.into())
+    }
+}
+
+impl<R: RngCore + CryptoRng> CryptoRng for ExpectRng<'_, R> {}
+
+#[cfg(test)]
+mod test {
+    use rand_core::SeedableRng;
+
+    use super::*;
+    use crate::{NistPqcAes256CtrRng, Seed};
+
+    #[test]
+    fn matching() {
+        let mut transcript = [0; 40];
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::default());
+        reference.fill_bytes(&mut transcript[..32]);
+        reference.fill_bytes(&mut transcript[32..]);
+
+        let mut rng = ExpectRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()), &transcript);
+        rng.fill_bytes(&mut [0; 32]);
+        assert!(!rng.is_complete());
+        rng.next_u64();
+        assert!(rng.is_complete());
+        assert_eq!(rng.offset(), 40);
+        assert!(rng.divergence().is_none());
+    }
+
+    #[test]
+    fn diverging() {
+        let mut transcript = [0; 16];
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::default());
+        reference.fill_bytes(&mut transcript[..4]);
+        reference.fill_bytes(&mut transcript[4..]);
+        transcript[10] ^= 1;
+
+        let mut rng = ExpectRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()), &transcript);
+        assert!(rng.try_fill_bytes(&mut [0; 4]).is_ok());
+        assert!(rng.try_fill_bytes(&mut [0; 12]).is_err());
+        assert!(rng.try_fill_bytes(&mut [0; 1]).is_err());
+        assert_eq!(
+            rng.divergence(),
+            Some(Divergence {
+                offset: 10,
+                call: 1
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "output diverges from the transcript at offset 2 in call 0")]
+    fn exhausted() {
+        let mut rng = ExpectRng::new(
+            NistPqcAes256CtrRng::from_seed(Seed::default()),
+            &[0x91, 0x61],
+        );
+        rng.next_u32();
+    }
+}
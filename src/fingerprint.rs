@@ -0,0 +1,140 @@
+//! Digests of the output of the seeded CTR_DRBG and adapters to the traits of
+//! the `digest` crate.
+
+use digest::{ExtendableOutput, FixedOutput, Output, Update, XofReader};
+use rand_core::RngCore;
+
+use crate::{CtrDrbgCipher, SeededCtrDrbg};
+
+/// Adapter implementing [XofReader] with the output of an RNG
+///
+/// This allows APIs written against XOF readers to consume the output of the
+/// RNG:
+/// ```
+/// use digest::XofReader;
+/// use nist_pqc_seeded_rng::{NistPqcAes256CtrRng, RngCore, RngXofReader, Seed, SeedableRng};
+///
+/// let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+/// let mut reader = RngXofReader::new(&mut rng);
+/// let mut buf = [0; 32];
+/// reader.read(&mut buf);
+/// ```
+///
+/// Every call to [XofReader::read] is a separate request to the RNG. Hence,
+/// unlike the output of an XOF, the output depends on how the reads are split.
+#[derive(Clone, Debug)]
+pub struct RngXofReader<R: RngCore> {
+    rng: R,
+}
+
+impl<R: RngCore> RngXofReader<R> {
+    /// Read the output of `rng`.
+    pub const fn new(rng: R) -> Self {
+        Self { rng }
+    }
+
+    /// Return the wrapped RNG.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+}
+
+impl<R: RngCore> XofReader for RngXofReader<R> {
+    fn read(&mut self, buffer: &mut [u8]) {
+        self.rng.fill_bytes(buffer);
+    }
+}
+
+impl<C: CtrDrbgCipher> SeededCtrDrbg<C> {
+    /// Hash the next `n` bytes of output with the hash function `D`, e.g.,
+    /// SHA-256.
+    ///
+    /// The output is consumed in a single request of `n` bytes and hashed
+    /// incrementally, so that long streams can be compared against other
+    /// implementations without storing them:
+    /// ```
+    /// use nist_pqc_seeded_rng::{NistPqcAes256CtrRng, Seed, SeedableRng};
+    /// use sha2::{Digest, Sha256};
+    ///
+    /// let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+    /// let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+    /// let digest = rng_1.output_digest::<Sha256>(1 << 16);
+    /// assert_eq!(digest, Sha256::digest(rng_2.generate_vec(1 << 16)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the RNG is strict and rejects the request.
+    pub fn output_digest<D: Default + FixedOutput + Update>(&mut self, n: usize) -> Output<D> {
+        let mut hasher = D::default();
+        self.stream_output(n, |bytes| hasher.update(bytes));
+        hasher.finalize_fixed()
+    }
+
+    /// Hash the next `n` bytes of output with the extendable-output function
+    /// `D`, e.g., SHAKE256, and return `N` bytes of digest.
+    ///
+    /// The output is consumed in a single request of `n` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the RNG is strict and rejects the request.
+    pub fn output_xof_digest<D: Default + ExtendableOutput + Update, const N: usize>(
+        &mut self,
+        n: usize,
+    ) -> [u8; N] {
+        let mut hasher = D::default();
+        self.stream_output(n, |bytes| hasher.update(bytes));
+        let mut digest = [0; N];
+        hasher.finalize_xof_into(&mut digest);
+        digest
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use digest::XofReader;
+    use rand_core::{RngCore, SeedableRng};
+    use sha2::{Digest, Sha256};
+    use sha3::{digest::ExtendableOutput, Shake256};
+
+    use super::RngXofReader;
+    use crate::{NistPqcAes256CtrRng, Seed};
+
+    #[test]
+    fn sha256() {
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut buf = [0; 1000];
+        rng_2.fill_bytes(&mut buf);
+        assert_eq!(rng_1.output_digest::<Sha256>(1000), Sha256::digest(buf));
+        assert_eq!(rng_1.reseed_counter(), 2);
+        assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+    }
+
+    #[test]
+    fn xof_reader() {
+        let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut reader = RngXofReader::new(&mut rng);
+        let mut buf = [0; 20];
+        reader.read(&mut buf[..4]);
+        reader.read(&mut buf[4..]);
+        let mut expected = [0; 20];
+        reference.fill_bytes(&mut expected[..4]);
+        reference.fill_bytes(&mut expected[4..]);
+        assert_eq!(buf, expected);
+        assert_eq!(rng.reseed_counter(), 3);
+    }
+
+    #[test]
+    fn shake256() {
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut buf = [0; 100];
+        rng_2.fill_bytes(&mut buf);
+        let mut expected = [0; 64];
+        Shake256::digest_xof(buf, &mut expected);
+        assert_eq!(rng_1.output_xof_digest::<Shake256, 64>(100), expected);
+    }
+}
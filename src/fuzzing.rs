@@ -0,0 +1,79 @@
+//! Support for generating seeds and RNG states from fuzzer input.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{ctr_drbg, CompatMode, CtrDrbgCipher, SeedN, SeededCtrDrbg, KEY_LENGTH};
+
+impl<'a, const N: usize> Arbitrary<'a> for SeedN<N> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary().map(Self)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; N]>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for CompatMode {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(if u.arbitrary()? {
+            Self::AlignedExtraBlock
+        } else {
+            Self::Reference
+        })
+    }
+}
+
+/// Generates arbitrary states of the DRBG including the reseed counter and
+/// the [CompatMode]. The RNGs are never strict.
+impl<'a, C: CtrDrbgCipher> Arbitrary<'a> for SeededCtrDrbg<C> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut key: [u8; KEY_LENGTH] = u.arbitrary()?;
+        // only the first `C::key_size()` bytes are used
+        key[C::key_size()..].fill(0);
+        let v = u.arbitrary()?;
+        let reseed_counter = u.int_in_range(1..=ctr_drbg::RESEED_INTERVAL)?;
+        let compat_mode = u.arbitrary()?;
+
+        let mut rng = Self::from_seed_with_compat_mode(C::Seed::default(), compat_mode);
+        rng.key = key;
+        rng.v = v;
+        rng.reseed_counter = reseed_counter;
+        Ok(rng)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand_core::RngCore;
+
+    use super::*;
+    use crate::{Aes128Seed, NistAes128CtrRng, NistPqcAes256CtrRng, Seed};
+
+    #[test]
+    fn seed() {
+        let data: [u8; 64] = core::array::from_fn(|i| i as u8);
+        let mut u = Unstructured::new(&data);
+        let seed = Seed::arbitrary(&mut u).unwrap();
+        assert_eq!(seed[..], data[..48]);
+        assert_eq!(Seed::size_hint(0), (48, Some(48)));
+        assert!(Aes128Seed::arbitrary(&mut u).is_ok());
+    }
+
+    #[test]
+    fn rng() {
+        let data: [u8; 128] = core::array::from_fn(|i| i as u8);
+        let mut rng = NistPqcAes256CtrRng::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(rng.key(), &data[..32]);
+        assert_eq!(rng.v()[..], data[32..48]);
+        rng.next_u64();
+
+        let rng = NistAes128CtrRng::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(rng.key(), &data[..16]);
+        assert_eq!(rng.key, {
+            let mut key = [0; KEY_LENGTH];
+            key[..16].copy_from_slice(&data[..16]);
+            key
+        });
+    }
+}
@@ -0,0 +1,184 @@
+//! Process-wide RNG mirroring the API of `rng.c`.
+//!
+//! The functions simplify line-by-line ports of `PQCgenKAT` programs:
+//! ```
+//! use nist_pqc_seeded_rng::global::{randombytes, randombytes_init};
+//!
+//! let entropy_input: [u8; 48] = core::array::from_fn(|i| i as u8);
+//! randombytes_init(&entropy_input, None);
+//! let mut seed = [0; 48];
+//! randombytes(&mut seed);
+//! ```
+//!
+//! The state is shared by all threads of the process. Hence, the output
+//! drawn by each thread is only deterministic if the threads synchronize
+//! their requests. Alternatively, the functions of [thread_local] give each
+//! thread its own RNG.
+
+use std::sync::{Mutex, PoisonError};
+
+use rand_core::{RngCore, SeedableRng};
+
+use crate::{NistPqcAes256CtrRng, Seed, SEED_LENGTH};
+
+static RNG: Mutex<Option<NistPqcAes256CtrRng>> = Mutex::new(None);
+
+/// Derive the seed from the entropy input and the personalization string.
+fn seed(
+    entropy_input: &[u8; SEED_LENGTH],
+    personalization_string: Option<&[u8; SEED_LENGTH]>,
+) -> Seed {
+    let mut seed = Seed::from(*entropy_input);
+    if let Some(personalization_string) = personalization_string {
+        seed.xor_with(personalization_string);
+    }
+    seed
+}
+
+/// Instantiate the process-wide RNG.
+///
+/// This is equivalent to `randombytes_init(entropy_input,
+/// personalization_string, 256)` of `rng.c`. A previously instantiated RNG is
+/// replaced.
+pub fn randombytes_init(
+    entropy_input: &[u8; SEED_LENGTH],
+    personalization_string: Option<&[u8; SEED_LENGTH]>,
+) {
+    let rng = NistPqcAes256CtrRng::from_seed(seed(entropy_input, personalization_string));
+    RNG.lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .replace(rng);
+}
+
+/// Fill `x` with output of the process-wide RNG.
+///
+/// This is equivalent to `randombytes(x, x.len())` of `rng.c`.
+///
+/// # Panics
+///
+/// Panics if the RNG has not been instantiated with [randombytes_init].
+pub fn randombytes(x: &mut [u8]) {
+    RNG.lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_mut()
+        .expect("global RNG not instantiated")
+        .fill_bytes(x);
+}
+
+/// Thread-local RNG mirroring the API of `rng.c`.
+///
+/// Each thread has its own RNG, e.g., to regenerate the KATs for different
+/// counts in parallel:
+/// ```
+/// use nist_pqc_seeded_rng::global::thread_local::{randombytes, randombytes_init};
+///
+/// std::thread::scope(|s| {
+///     for count in 0..4 {
+///         s.spawn(move || {
+///             randombytes_init(&[count; 48], None);
+///             let mut coins = [0; 32];
+///             randombytes(&mut coins);
+///         });
+///     }
+/// });
+/// ```
+pub mod thread_local {
+    use core::cell::RefCell;
+
+    use rand_core::{RngCore, SeedableRng};
+
+    use crate::{NistPqcAes256CtrRng, SEED_LENGTH};
+
+    std::thread_local! {
+        static RNG: RefCell<Option<NistPqcAes256CtrRng>> = const { RefCell::new(None) };
+    }
+
+    /// Instantiate the RNG of the current thread.
+    ///
+    /// This is equivalent to `randombytes_init(entropy_input,
+    /// personalization_string, 256)` of `rng.c`. A previously instantiated RNG
+    /// of the thread is replaced.
+    pub fn randombytes_init(
+        entropy_input: &[u8; SEED_LENGTH],
+        personalization_string: Option<&[u8; SEED_LENGTH]>,
+    ) {
+        let rng =
+            NistPqcAes256CtrRng::from_seed(super::seed(entropy_input, personalization_string));
+        RNG.with(|cell| cell.borrow_mut().replace(rng));
+    }
+
+    /// Fill `x` with output of the RNG of the current thread.
+    ///
+    /// This is equivalent to `randombytes(x, x.len())` of `rng.c`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the RNG of the thread has not been instantiated with
+    /// [randombytes_init].
+    pub fn randombytes(x: &mut [u8]) {
+        RNG.with(|cell| {
+            cell.borrow_mut()
+                .as_mut()
+                .expect("thread-local RNG not instantiated")
+                .fill_bytes(x)
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn global() {
+        let entropy_input = [1; SEED_LENGTH];
+        let personalization_string = [2; SEED_LENGTH];
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::from([3; SEED_LENGTH]));
+        let mut expected = [0; 32];
+        reference.fill_bytes(&mut expected);
+
+        randombytes_init(&entropy_input, Some(&personalization_string));
+        let mut buf = [0; 32];
+        randombytes(&mut buf);
+        assert_eq!(buf, expected);
+
+        randombytes_init(&[3; SEED_LENGTH], None);
+        randombytes(&mut buf);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn thread_local() {
+        let outputs: std::vec::Vec<[u8; 32]> = std::thread::scope(|s| {
+            let handles: std::vec::Vec<_> = (0..4)
+                .map(|count| {
+                    s.spawn(move || {
+                        thread_local::randombytes_init(&[count; SEED_LENGTH], None);
+                        let mut buf = [0; 32];
+                        thread_local::randombytes(&mut buf);
+                        buf
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+        for (count, output) in outputs.into_iter().enumerate() {
+            let mut rng = NistPqcAes256CtrRng::from_seed(Seed::from([count as u8; SEED_LENGTH]));
+            let mut expected = [0; 32];
+            rng.fill_bytes(&mut expected);
+            assert_eq!(output, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "thread-local RNG not instantiated")]
+    fn thread_local_not_instantiated() {
+        let result = std::thread::spawn(|| thread_local::randombytes(&mut [0; 1])).join();
+        if let Err(err) = result {
+            std::panic::resume_unwind(err);
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Zeroization of transient secret material.
+
+use core::ops::{Deref, DerefMut};
+
+/// Guard zeroizing the borrowed buffer on drop if the `zeroize` feature is
+/// enabled
+///
+/// The guard is used for temporary buffers holding key material, seeds or
+/// outputs, so that they are also cleared on early returns.
+pub(crate) struct ZeroizeGuard<'a>(&'a mut [u8]);
+
+impl<'a> ZeroizeGuard<'a> {
+    pub(crate) fn new(buffer: &'a mut [u8]) -> Self {
+        Self(buffer)
+    }
+}
+
+impl Deref for ZeroizeGuard<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl DerefMut for ZeroizeGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0
+    }
+}
+
+impl Drop for ZeroizeGuard<'_> {
+    fn drop(&mut self) {
+        zeroize(self.0);
+    }
+}
+
+/// Zeroize the buffer if the `zeroize` feature is enabled.
+///
+/// Used for buffers that are fields of other types and therefore cannot be
+/// borrowed by a guard.
+#[cfg_attr(not(feature = "zeroize"), allow(unused_variables))]
+pub(crate) fn zeroize(buffer: &mut [u8]) {
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(buffer);
+    #[cfg(all(test, feature = "zeroize", feature = "std"))]
+    ZEROIZED.with(|zeroized| zeroized.borrow_mut().push(buffer.len()));
+}
+
+#[cfg(all(test, feature = "zeroize", feature = "std"))]
+std::thread_local! {
+    static ZEROIZED: core::cell::RefCell<std::vec::Vec<usize>> =
+        const { core::cell::RefCell::new(std::vec::Vec::new()) };
+}
+
+/// Lengths of the buffers zeroized on the current thread since the last call
+///
+/// Allows tests to check that the transient buffers of a call site are
+/// zeroized.
+#[cfg(all(test, feature = "zeroize", feature = "std"))]
+pub(crate) fn take_zeroized() -> std::vec::Vec<usize> {
+    ZEROIZED.with(|zeroized| zeroized.take())
+}
+
+#[cfg(all(test, feature = "zeroize"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn zeroized_on_drop() {
+        let mut buffer = [0xff; 16];
+        {
+            let mut guard = ZeroizeGuard::new(&mut buffer[..8]);
+            guard.iter_mut().for_each(|byte| *byte = 1);
+        }
+        assert_eq!(buffer[..8], [0; 8]);
+        assert_eq!(buffer[8..], [0xff; 8]);
+    }
+
+    #[test]
+    fn zeroized_on_early_return() {
+        fn fill_and_fail(buffer: &mut [u8]) -> Result<(), ()> {
+            let mut guard = ZeroizeGuard::new(buffer);
+            guard.fill(1);
+            Err(())
+        }
+
+        let mut buffer = [0xff; 16];
+        assert!(fill_and_fail(&mut buffer).is_err());
+        assert_eq!(buffer, [0; 16]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn zeroized_on_panic() {
+        let mut buffer = [0xff; 16];
+        take_zeroized();
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            let mut guard = ZeroizeGuard::new(&mut buffer);
+            guard.fill(1);
+            panic!("request failed");
+        }));
+        assert!(result.is_err());
+        assert_eq!(buffer, [0; 16]);
+        assert_eq!(take_zeroized(), [16]);
+    }
+}
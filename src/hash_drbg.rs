@@ -0,0 +1,343 @@
+//! Hash_DRBG as specified in NIST SP 800-90A Rev. 1 using SHA-256.
+
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+use sha2::{Digest, Sha256};
+
+use crate::{guard::ZeroizeGuard, Error, Seed};
+
+/// Maximum number of bytes that can be requested with a single call to
+/// [HashDrbgSha256::generate].
+pub const MAX_BYTES_PER_REQUEST: usize = 1 << 16;
+/// Maximum number of requests between reseeds.
+pub const RESEED_INTERVAL: u64 = 1 << 48;
+
+const OUTPUT_LENGTH: usize = 32;
+const SEED_LENGTH: usize = 55;
+
+/// Add `value` interpreted as big-endian integer to `acc` modulo
+/// `2^(8 * SEED_LENGTH)`.
+fn add_assign(acc: &mut [u8], value: &[u8]) {
+    let mut carry = 0u16;
+    for (i, byte) in acc.iter_mut().rev().enumerate() {
+        let sum = *byte as u16
+            + value
+                .len()
+                .checked_sub(i + 1)
+                .map_or(0, |j| value[j] as u16)
+            + carry;
+        *byte = sum as u8;
+        carry = sum >> 8;
+    }
+}
+
+/// `Hash_df` (cf. SP 800-90A, section 10.3.1) applied to the concatenation of
+/// `inputs`.
+fn hash_df(inputs: &[&[u8]]) -> [u8; SEED_LENGTH] {
+    let mut output = [0; SEED_LENGTH];
+    for (counter, chunk) in output.chunks_mut(OUTPUT_LENGTH).enumerate() {
+        let mut hash = Sha256::new();
+        hash.update([counter as u8 + 1]);
+        hash.update(((SEED_LENGTH * 8) as u32).to_be_bytes());
+        inputs.iter().for_each(|input| hash.update(input));
+        chunk.copy_from_slice(&hash.finalize()[..chunk.len()]);
+    }
+    output
+}
+
+/// Hash_DRBG using SHA-256
+///
+/// This RNG implements the instantiate, reseed and generate functions of
+/// Hash_DRBG from NIST SP 800-90A including the reseed counter.
+#[derive(Debug)]
+#[cfg_attr(feature = "zeroize", derive(zeroize::ZeroizeOnDrop))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HashDrbgSha256 {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    v: [u8; SEED_LENGTH],
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    c: [u8; SEED_LENGTH],
+    reseed_counter: u64,
+}
+
+impl HashDrbgSha256 {
+    /// Instantiate the DRBG from an entropy input (that may include a nonce)
+    /// and a personalization string.
+    pub fn new(entropy_input: &[u8], personalization_string: &[u8]) -> Self {
+        let v = hash_df(&[entropy_input, personalization_string]);
+        let c = hash_df(&[&[0x00], &v]);
+        Self {
+            v,
+            c,
+            reseed_counter: 1,
+        }
+    }
+
+    /// Reseed the DRBG with a fresh entropy input and additional input.
+    pub fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) {
+        self.v = hash_df(&[&[0x01], &self.v, entropy_input, additional_input]);
+        self.c = hash_df(&[&[0x00], &self.v]);
+        self.reseed_counter = 1;
+    }
+
+    /// Fill `dest` with random bytes taking the additional input into account.
+    ///
+    /// Fails if more than [MAX_BYTES_PER_REQUEST] bytes are requested or if
+    /// the DRBG needs to be reseeded.
+    pub fn generate(&mut self, dest: &mut [u8], additional_input: &[u8]) -> Result<(), Error> {
+        if self.reseed_counter > RESEED_INTERVAL {
+            return Err(Error::ReseedRequired);
+        }
+        if dest.len() > MAX_BYTES_PER_REQUEST {
+            return Err(Error::RequestTooLarge);
+        }
+
+        if !additional_input.is_empty() {
+            let w = Sha256::new()
+                .chain_update([0x02])
+                .chain_update(self.v)
+                .chain_update(additional_input)
+                .finalize();
+            add_assign(&mut self.v, &w);
+        }
+
+        // Hashgen
+        let mut data = self.v;
+        let mut data = ZeroizeGuard::new(&mut data);
+        for chunk in dest.chunks_mut(OUTPUT_LENGTH) {
+            let w = Sha256::digest(&*data);
+            chunk.copy_from_slice(&w[..chunk.len()]);
+            add_assign(&mut data, &[1]);
+        }
+
+        let h = Sha256::new()
+            .chain_update([0x03])
+            .chain_update(self.v)
+            .finalize();
+        let mut c = self.c;
+        let c = ZeroizeGuard::new(&mut c);
+        add_assign(&mut self.v, &h);
+        add_assign(&mut self.v, &c);
+        add_assign(&mut self.v, &self.reseed_counter.to_be_bytes());
+        self.reseed_counter += 1;
+        Ok(())
+    }
+}
+
+impl SeedableRng for HashDrbgSha256 {
+    type Seed = Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(seed.as_ref(), &[])
+    }
+}
+
+impl RngCore for HashDrbgSha256 {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).expect("reseed required")
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        for chunk in dest.chunks_mut(MAX_BYTES_PER_REQUEST) {
+            self.generate(chunk, &[])?;
+        }
+        Ok(())
+    }
+}
+
+impl CryptoRng for HashDrbgSha256 {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ENTROPY_INPUT: [u8; 48] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+        0x1e, 0x1f, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c,
+        0x2d, 0x2e, 0x2f,
+    ];
+
+    fn range<const N: usize>(start: u8) -> [u8; N] {
+        let mut buf = [0; N];
+        buf.iter_mut()
+            .enumerate()
+            .for_each(|(i, byte)| *byte = start + i as u8);
+        buf
+    }
+
+    #[test]
+    fn add() {
+        let mut acc = [0xff; SEED_LENGTH];
+        add_assign(&mut acc, &[1]);
+        assert_eq!(acc, [0; SEED_LENGTH]);
+        acc[SEED_LENGTH - 1] = 0xff;
+        add_assign(&mut acc, &[0x01, 0x01]);
+        assert_eq!(acc[SEED_LENGTH - 3..], [0x00, 0x02, 0x00]);
+    }
+
+    // The expected values were cross-checked against the Hash-DRBG of OpenSSL 3
+    // unless the test uses a CAVP vector. The entropy inputs of the CAVP
+    // vectors are the concatenation of `EntropyInput` and `Nonce`.
+
+    /// CAVP Hash_DRBG vector (SHA-256, no reseed, COUNT = 0)
+    #[test]
+    fn cavp_no_additional_input() {
+        let entropy_input = [
+            0xa6, 0x5a, 0xd0, 0xf3, 0x45, 0xdb, 0x4e, 0x0e, 0xff, 0xe8, 0x75, 0xc3, 0xa2, 0xe7,
+            0x1f, 0x42, 0xc7, 0x12, 0x9d, 0x62, 0x0f, 0xf5, 0xc1, 0x19, 0xa9, 0xef, 0x55, 0xf0,
+            0x51, 0x85, 0xe0, 0xfb, 0x85, 0x81, 0xf9, 0x31, 0x75, 0x17, 0x27, 0x6e, 0x06, 0xe9,
+            0x60, 0x7d, 0xdb, 0xcb, 0xcc, 0x2e,
+        ];
+        let mut rng = HashDrbgSha256::new(&entropy_input, &[]);
+        let mut buf = [0; 128];
+        rng.generate(&mut buf, &[]).unwrap();
+        rng.generate(&mut buf, &[]).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0xd3, 0xe1, 0x60, 0xc3, 0x5b, 0x99, 0xf3, 0x40, 0xb2, 0x62, 0x82, 0x64, 0xd1, 0x75,
+                0x10, 0x60, 0xe0, 0x04, 0x5d, 0xa3, 0x83, 0xff, 0x57, 0xa5, 0x7d, 0x73, 0xa6, 0x73,
+                0xd2, 0xb8, 0xd8, 0x0d, 0xaa, 0xf6, 0xa6, 0xc3, 0x5a, 0x91, 0xbb, 0x45, 0x79, 0xd7,
+                0x3f, 0xd0, 0xc8, 0xfe, 0xd1, 0x11, 0xb0, 0x39, 0x13, 0x06, 0x82, 0x8a, 0xdf, 0xed,
+                0x52, 0x8f, 0x01, 0x81, 0x21, 0xb3, 0xfe, 0xbd, 0xc3, 0x43, 0xe7, 0x97, 0xb8, 0x7d,
+                0xbb, 0x63, 0xdb, 0x13, 0x33, 0xde, 0xd9, 0xd1, 0xec, 0xe1, 0x77, 0xcf, 0xa6, 0xb7,
+                0x1f, 0xe8, 0xab, 0x1d, 0xa4, 0x66, 0x24, 0xed, 0x64, 0x15, 0xe5, 0x1c, 0xcd, 0xe2,
+                0xc7, 0xca, 0x86, 0xe2, 0x83, 0x99, 0x0e, 0xea, 0xeb, 0x91, 0x12, 0x04, 0x15, 0x52,
+                0x8b, 0x22, 0x95, 0x91, 0x02, 0x81, 0xb0, 0x2d, 0xd4, 0x31, 0xf4, 0xc9, 0xf7, 0x04,
+                0x27, 0xdf,
+            ]
+        );
+    }
+
+    /// CAVP Hash_DRBG vector (SHA-256, no reseed, `AdditionalInputLen = 256`,
+    /// COUNT = 0)
+    #[test]
+    fn cavp_additional_input() {
+        let entropy_input = [
+            0x73, 0xd3, 0xfb, 0xa3, 0x94, 0x5f, 0x2b, 0x5f, 0xb9, 0x8f, 0xf6, 0x9c, 0x8a, 0x93,
+            0x17, 0xae, 0x19, 0xc3, 0x4c, 0xc3, 0xd6, 0xca, 0xa3, 0x2d, 0x16, 0xfc, 0x42, 0xd2,
+            0x2d, 0xd5, 0x6f, 0x56, 0xcc, 0x1d, 0x30, 0xff, 0x9e, 0x06, 0x3e, 0x09, 0xce, 0x58,
+            0xe6, 0x9a, 0x35, 0xb3, 0xa6, 0x56,
+        ];
+        let mut rng = HashDrbgSha256::new(&entropy_input, &[]);
+        let mut buf = [0; 128];
+        rng.generate(
+            &mut buf,
+            &[
+                0xf4, 0xd5, 0x98, 0x3d, 0xa8, 0xfc, 0xfa, 0x37, 0xb7, 0x54, 0x67, 0x73, 0xc7, 0xc3,
+                0xdd, 0x47, 0x34, 0x71, 0x02, 0x5d, 0xc1, 0xa0, 0xd3, 0x10, 0xc1, 0x8b, 0xbd, 0xf5,
+                0x66, 0x34, 0x6f, 0xdd,
+            ],
+        )
+        .unwrap();
+        rng.generate(
+            &mut buf,
+            &[
+                0xf7, 0x9e, 0x6a, 0x56, 0x0e, 0x73, 0xe9, 0xd9, 0x7a, 0xd1, 0x69, 0xe0, 0x6f, 0x8c,
+                0x55, 0x1c, 0x44, 0xd1, 0xce, 0x6f, 0x28, 0xcc, 0xa4, 0x4d, 0xa8, 0xc0, 0x85, 0xd1,
+                0x5a, 0x0c, 0x59, 0x40,
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x71, 0x7b, 0x93, 0x46, 0x1a, 0x40, 0xaa, 0x35, 0xa4, 0xaa, 0xc5, 0xe7, 0x6d, 0x5b,
+                0x5b, 0x8a, 0xa0, 0xdf, 0x39, 0x7d, 0xae, 0x71, 0x58, 0x5b, 0x3c, 0x7c, 0xb4, 0xf0,
+                0x89, 0xfa, 0x4a, 0x8c, 0xa9, 0x5c, 0x54, 0xc0, 0x40, 0xdf, 0xbc, 0xce, 0x26, 0x81,
+                0x34, 0xf8, 0xba, 0x7d, 0x1c, 0xe8, 0xad, 0x21, 0xe0, 0x74, 0xcf, 0x48, 0x84, 0x30,
+                0x1f, 0xa1, 0xd5, 0x4f, 0x81, 0x42, 0x2f, 0xf4, 0xdb, 0x0b, 0x23, 0xf8, 0x73, 0x27,
+                0xb8, 0x1d, 0x42, 0xf8, 0x44, 0x58, 0xd8, 0x5b, 0x29, 0x27, 0x0a, 0xf8, 0x69, 0x59,
+                0xb5, 0x78, 0x44, 0xeb, 0x9e, 0xe0, 0x68, 0x6f, 0x42, 0x9a, 0xb0, 0x5b, 0xe0, 0x4e,
+                0xcb, 0x6a, 0xaa, 0xe2, 0xd2, 0xd5, 0x33, 0x25, 0x3e, 0xe0, 0x6c, 0xc7, 0x6a, 0x07,
+                0xa5, 0x03, 0x83, 0x9f, 0xe2, 0x8b, 0xd1, 0x1c, 0x70, 0xa8, 0x07, 0x59, 0x97, 0xeb,
+                0xf6, 0xbe,
+            ]
+        );
+    }
+
+    #[test]
+    fn personalization_and_additional_input() {
+        let mut rng = HashDrbgSha256::new(&ENTROPY_INPUT, &range::<32>(0x40));
+        let mut buf = [0; 128];
+        rng.generate(&mut buf, &range::<32>(0x60)).unwrap();
+        rng.generate(&mut buf, &range::<32>(0xa0)).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x0d, 0x0c, 0x4c, 0x06, 0x2b, 0x1c, 0x8f, 0x48, 0x9b, 0x68, 0xc6, 0x11, 0x1c, 0x3b,
+                0x5b, 0x79, 0x0d, 0x67, 0xf1, 0x25, 0x0f, 0x9c, 0xca, 0x6b, 0xed, 0x1e, 0x98, 0x1d,
+                0x8e, 0x0d, 0x25, 0x3e, 0x5c, 0xcb, 0xc1, 0x67, 0x4c, 0xc7, 0x57, 0x8a, 0x48, 0x8e,
+                0x61, 0xcc, 0xa8, 0x22, 0x21, 0xb7, 0x31, 0x50, 0x07, 0xaa, 0x3c, 0x64, 0x95, 0x95,
+                0xf9, 0x8a, 0x72, 0x69, 0xe5, 0xef, 0xf9, 0x21, 0x71, 0x03, 0x32, 0x18, 0xdd, 0xff,
+                0x8e, 0x3d, 0x47, 0x63, 0x02, 0xb4, 0x9b, 0xd5, 0xfb, 0x89, 0x6d, 0x87, 0xd7, 0xbe,
+                0x3e, 0x56, 0x62, 0xf3, 0x28, 0xdb, 0x5f, 0x02, 0x3c, 0x88, 0x26, 0x8f, 0x73, 0x2e,
+                0x4e, 0xde, 0x89, 0x8a, 0x58, 0x5f, 0xb1, 0x21, 0x1e, 0x11, 0x4e, 0x0f, 0xee, 0x0c,
+                0xa4, 0x22, 0x6b, 0x6e, 0x4c, 0x5f, 0x49, 0xe6, 0x16, 0x33, 0x48, 0xc0, 0xd5, 0xb6,
+                0x93, 0x75,
+            ]
+        );
+    }
+
+    #[test]
+    fn reseed() {
+        let mut rng = HashDrbgSha256::from_seed(ENTROPY_INPUT.into());
+        rng.reseed(&range::<32>(0x80), &range::<32>(0x60));
+        let mut buf = [0; 128];
+        rng.generate(&mut buf, &[]).unwrap();
+        rng.generate(&mut buf, &range::<32>(0xa0)).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x4d, 0x6e, 0x8f, 0x85, 0xe9, 0x99, 0xb9, 0xcf, 0x2f, 0x28, 0x44, 0x48, 0xb4, 0x98,
+                0x15, 0xdb, 0x4e, 0x98, 0x0f, 0xed, 0x09, 0x94, 0xef, 0xe9, 0x5e, 0x10, 0x07, 0x32,
+                0xc4, 0x9f, 0xe1, 0xa3, 0x6c, 0xc3, 0x10, 0x02, 0x2d, 0x5e, 0x4b, 0x20, 0x66, 0x94,
+                0xac, 0x88, 0x38, 0xf3, 0x3f, 0x85, 0xe7, 0x57, 0xb8, 0x7f, 0x56, 0x3d, 0xcc, 0x21,
+                0x49, 0x66, 0x7c, 0x3e, 0x31, 0x9c, 0x49, 0xd8, 0x0d, 0xb0, 0xb2, 0xb0, 0xa1, 0x2f,
+                0x92, 0x86, 0xc0, 0xd7, 0x7f, 0x29, 0xbb, 0xc7, 0xd5, 0x75, 0xef, 0xc2, 0x38, 0x61,
+                0x07, 0x08, 0xe5, 0x1f, 0x05, 0x7e, 0xf0, 0x94, 0xe6, 0xbe, 0x01, 0x46, 0x2c, 0xc2,
+                0xcd, 0x04, 0xf6, 0x09, 0xcf, 0x1b, 0xd1, 0x46, 0x7c, 0xde, 0x66, 0xbe, 0x6b, 0xd2,
+                0xb8, 0x40, 0x81, 0xd5, 0x64, 0x52, 0xe4, 0x56, 0x1d, 0x9e, 0x9a, 0x3e, 0x4d, 0xc7,
+                0x18, 0xe9,
+            ]
+        );
+    }
+
+    #[test]
+    fn request_limits() {
+        let mut rng = HashDrbgSha256::from_seed(ENTROPY_INPUT.into());
+        let mut buf = [0; MAX_BYTES_PER_REQUEST + 1];
+        assert_eq!(rng.generate(&mut buf, &[]), Err(Error::RequestTooLarge));
+        assert!(rng.try_fill_bytes(&mut buf).is_ok());
+
+        rng.reseed_counter = RESEED_INTERVAL + 1;
+        assert_eq!(
+            rng.generate(&mut buf[..16], &[]),
+            Err(Error::ReseedRequired)
+        );
+        rng.reseed(&ENTROPY_INPUT, &[]);
+        assert!(rng.generate(&mut buf[..16], &[]).is_ok());
+    }
+
+    #[cfg(all(feature = "zeroize", feature = "std"))]
+    #[test]
+    fn zeroize_buffers() {
+        let mut rng = HashDrbgSha256::new(&ENTROPY_INPUT, &[]);
+        crate::guard::take_zeroized();
+        rng.generate(&mut [0; 64], &[]).unwrap();
+        // the data of Hashgen and the copy of C
+        assert_eq!(crate::guard::take_zeroized(), [SEED_LENGTH, SEED_LENGTH]);
+    }
+}
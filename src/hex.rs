@@ -0,0 +1,112 @@
+//! Hex encoding of seeds as used in the KAT files.
+
+use core::fmt;
+
+use crate::ParseSeedError;
+
+/// Write `bytes` as hex string with lowercase or uppercase digits.
+pub(crate) fn encode(f: &mut impl fmt::Write, bytes: &[u8], upper: bool) -> fmt::Result {
+    bytes.iter().try_for_each(|byte| {
+        if upper {
+            write!(f, "{:02X}", byte)
+        } else {
+            write!(f, "{:02x}", byte)
+        }
+    })
+}
+
+/// Decode the hex string `s` into `out`.
+///
+/// Both lowercase and uppercase digits are accepted. The string has to encode
+/// exactly `out.len()` bytes.
+pub(crate) fn decode(s: &str, out: &mut [u8]) -> Result<(), ParseSeedError> {
+    if s.len() != 2 * out.len() {
+        return Err(ParseSeedError::InvalidLength {
+            expected: 2 * out.len(),
+            actual: s.len(),
+        });
+    }
+
+    for (index, (byte, digits)) in out.iter_mut().zip(s.as_bytes().chunks_exact(2)).enumerate() {
+        let high = digit(digits[0]).ok_or(ParseSeedError::InvalidCharacter { index: 2 * index })?;
+        let low = digit(digits[1]).ok_or(ParseSeedError::InvalidCharacter {
+            index: 2 * index + 1,
+        })?;
+        *byte = high << 4 | low;
+    }
+    Ok(())
+}
+
+/// Decode the hex string `s` in const contexts.
+///
+/// # Panics
+///
+/// Panics if the string does not consist of exactly `2 * N` hex digits.
+pub const fn decode_array<const N: usize>(s: &str) -> [u8; N] {
+    let s = s.as_bytes();
+    assert!(s.len() == 2 * N, "hex string of invalid length");
+
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < N {
+        match (digit(s[2 * i]), digit(s[2 * i + 1])) {
+            (Some(high), Some(low)) => out[i] = high << 4 | low,
+            _ => panic!("invalid hex character"),
+        }
+        i += 1;
+    }
+    out
+}
+
+const fn digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decode_mixed_case() {
+        let mut out = [0; 4];
+        decode("00fFa19B", &mut out).unwrap();
+        assert_eq!(out, [0x00, 0xff, 0xa1, 0x9b]);
+    }
+
+    #[test]
+    fn decode_array_mixed_case() {
+        const OUT: [u8; 4] = decode_array("00fFa19B");
+        assert_eq!(OUT, [0x00, 0xff, 0xa1, 0x9b]);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hex character")]
+    fn decode_array_invalid() {
+        decode_array::<2>("00g0");
+    }
+
+    #[test]
+    fn decode_invalid() {
+        let mut out = [0; 2];
+        assert_eq!(
+            decode("001", &mut out),
+            Err(ParseSeedError::InvalidLength {
+                expected: 4,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            decode("00g0", &mut out),
+            Err(ParseSeedError::InvalidCharacter { index: 2 })
+        );
+        assert_eq!(
+            decode("0+00", &mut out),
+            Err(ParseSeedError::InvalidCharacter { index: 1 })
+        );
+    }
+}
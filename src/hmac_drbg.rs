@@ -0,0 +1,439 @@
+//! HMAC_DRBG as specified in NIST SP 800-90A Rev. 1.
+
+use core::marker::PhantomData;
+
+use hmac::{
+    digest::{core_api::BlockSizeUser, Digest},
+    Mac, SimpleHmac,
+};
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+use sha2::{Sha256, Sha512};
+
+use crate::{Error, Seed};
+
+/// Maximum number of bytes that can be requested with a single call to
+/// [HmacDrbg::generate].
+pub const MAX_BYTES_PER_REQUEST: usize = 1 << 16;
+/// Maximum number of requests between reseeds.
+pub const RESEED_INTERVAL: u64 = 1 << 48;
+
+/// Maximum supported output length of the hash function.
+const MAX_OUTPUT_LENGTH: usize = 64;
+
+/// HMAC_DRBG generic over the hash function
+///
+/// This RNG implements the instantiate, reseed and generate functions of
+/// HMAC_DRBG from NIST SP 800-90A including the reseed counter. Hash functions
+/// with an output length of up to 512 bits are supported.
+///
+/// Independent of the hash function, the [Seed] consists of 48 bytes, i.e.,
+/// an entropy input of 256 bits and a nonce of 128 bits as in the CAVP
+/// vectors. This is the full security strength of HMAC_DRBG with SHA-256 and
+/// SHA-512.
+#[derive(Debug)]
+#[cfg_attr(feature = "zeroize", derive(zeroize::ZeroizeOnDrop))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HmacDrbg<D> {
+    // only the first `D::output_size()` bytes are used
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    key: [u8; MAX_OUTPUT_LENGTH],
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    v: [u8; MAX_OUTPUT_LENGTH],
+    reseed_counter: u64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    digest: PhantomData<D>,
+}
+
+/// HMAC_DRBG using HMAC-SHA-256
+pub type HmacDrbgSha256 = HmacDrbg<Sha256>;
+
+/// HMAC_DRBG using HMAC-SHA-512
+pub type HmacDrbgSha512 = HmacDrbg<Sha512>;
+
+impl<D> HmacDrbg<D>
+where
+    D: Digest + BlockSizeUser,
+{
+    /// Instantiate the DRBG from an entropy input (that may include a nonce)
+    /// and a personalization string.
+    pub fn new(entropy_input: &[u8], personalization_string: &[u8]) -> Self {
+        assert!(<D as Digest>::output_size() <= MAX_OUTPUT_LENGTH);
+
+        let mut rng = Self {
+            key: [0; MAX_OUTPUT_LENGTH],
+            v: [0; MAX_OUTPUT_LENGTH],
+            reseed_counter: 1,
+            digest: PhantomData,
+        };
+        rng.v[..<D as Digest>::output_size()].fill(1);
+        rng.update(&[entropy_input, personalization_string]);
+        rng
+    }
+
+    /// Reseed the DRBG with a fresh entropy input and additional input.
+    pub fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) {
+        self.update(&[entropy_input, additional_input]);
+        self.reseed_counter = 1;
+    }
+
+    /// Fill `dest` with random bytes taking the additional input into account.
+    ///
+    /// Fails if more than [MAX_BYTES_PER_REQUEST] bytes are requested or if
+    /// the DRBG needs to be reseeded.
+    pub fn generate(&mut self, dest: &mut [u8], additional_input: &[u8]) -> Result<(), Error> {
+        if self.reseed_counter > RESEED_INTERVAL {
+            return Err(Error::ReseedRequired);
+        }
+        if dest.len() > MAX_BYTES_PER_REQUEST {
+            return Err(Error::RequestTooLarge);
+        }
+
+        if !additional_input.is_empty() {
+            self.update(&[additional_input]);
+        }
+        for chunk in dest.chunks_mut(<D as Digest>::output_size()) {
+            self.update_v();
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+        self.update(&[additional_input]);
+        self.reseed_counter += 1;
+        Ok(())
+    }
+
+    /// Create a HMAC instance with the current key.
+    fn mac(&self) -> SimpleHmac<D> {
+        SimpleHmac::new_from_slice(&self.key[..<D as Digest>::output_size()])
+            .expect("HMAC accepts keys of any size")
+    }
+
+    /// Compute `V = HMAC(Key, V)`.
+    fn update_v(&mut self) {
+        let output_length = <D as Digest>::output_size();
+        let mut mac = self.mac();
+        mac.update(&self.v[..output_length]);
+        self.v[..output_length].copy_from_slice(&mac.finalize().into_bytes());
+    }
+
+    /// `HMAC_DRBG_Update` with the concatenation of `provided_data`.
+    fn update(&mut self, provided_data: &[&[u8]]) {
+        let output_length = <D as Digest>::output_size();
+        for separator in [0x00, 0x01] {
+            if separator == 0x01 && provided_data.iter().all(|data| data.is_empty()) {
+                break;
+            }
+
+            let mut mac = self.mac();
+            mac.update(&self.v[..output_length]);
+            mac.update(&[separator]);
+            provided_data.iter().for_each(|data| mac.update(data));
+            self.key[..output_length].copy_from_slice(&mac.finalize().into_bytes());
+            self.update_v();
+        }
+    }
+}
+
+impl<D> SeedableRng for HmacDrbg<D>
+where
+    D: Digest + BlockSizeUser,
+{
+    type Seed = Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(seed.as_ref(), &[])
+    }
+}
+
+impl<D> RngCore for HmacDrbg<D>
+where
+    D: Digest + BlockSizeUser,
+{
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).expect("reseed required")
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        for chunk in dest.chunks_mut(MAX_BYTES_PER_REQUEST) {
+            self.generate(chunk, &[])?;
+        }
+        Ok(())
+    }
+}
+
+impl<D> CryptoRng for HmacDrbg<D> where D: Digest + BlockSizeUser {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ENTROPY_INPUT: [u8; 48] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+        0x1e, 0x1f, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c,
+        0x2d, 0x2e, 0x2f,
+    ];
+
+    fn range<const N: usize>(start: u8) -> [u8; N] {
+        let mut buf = [0; N];
+        buf.iter_mut()
+            .enumerate()
+            .for_each(|(i, byte)| *byte = start + i as u8);
+        buf
+    }
+
+    // The expected values were cross-checked against the HMAC-DRBG of OpenSSL 3
+    // unless the test uses a CAVP vector. The entropy inputs of the CAVP
+    // vectors are the concatenation of `EntropyInput` and `Nonce`.
+
+    /// CAVP HMAC_DRBG vector (SHA-256, no reseed, COUNT = 0)
+    #[test]
+    fn cavp_no_additional_input() {
+        let entropy_input = [
+            0xca, 0x85, 0x19, 0x11, 0x34, 0x93, 0x84, 0xbf, 0xfe, 0x89, 0xde, 0x1c, 0xbd, 0xc4,
+            0x6e, 0x68, 0x31, 0xe4, 0x4d, 0x34, 0xa4, 0xfb, 0x93, 0x5e, 0xe2, 0x85, 0xdd, 0x14,
+            0xb7, 0x1a, 0x74, 0x88, 0x65, 0x9b, 0xa9, 0x6c, 0x60, 0x1d, 0xc6, 0x9f, 0xc9, 0x02,
+            0x94, 0x08, 0x05, 0xec, 0x0c, 0xa8,
+        ];
+        let mut rng = HmacDrbgSha256::new(&entropy_input, &[]);
+        let mut buf = [0; 128];
+        rng.generate(&mut buf, &[]).unwrap();
+        rng.generate(&mut buf, &[]).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0xe5, 0x28, 0xe9, 0xab, 0xf2, 0xde, 0xce, 0x54, 0xd4, 0x7c, 0x7e, 0x75, 0xe5, 0xfe,
+                0x30, 0x21, 0x49, 0xf8, 0x17, 0xea, 0x9f, 0xb4, 0xbe, 0xe6, 0xf4, 0x19, 0x96, 0x97,
+                0xd0, 0x4d, 0x5b, 0x89, 0xd5, 0x4f, 0xbb, 0x97, 0x8a, 0x15, 0xb5, 0xc4, 0x43, 0xc9,
+                0xec, 0x21, 0x03, 0x6d, 0x24, 0x60, 0xb6, 0xf7, 0x3e, 0xba, 0xd0, 0xdc, 0x2a, 0xba,
+                0x6e, 0x62, 0x4a, 0xbf, 0x07, 0x74, 0x5b, 0xc1, 0x07, 0x69, 0x4b, 0xb7, 0x54, 0x7b,
+                0xb0, 0x99, 0x5f, 0x70, 0xde, 0x25, 0xd6, 0xb2, 0x9e, 0x2d, 0x30, 0x11, 0xbb, 0x19,
+                0xd2, 0x76, 0x76, 0xc0, 0x71, 0x62, 0xc8, 0xb5, 0xcc, 0xde, 0x06, 0x68, 0x96, 0x1d,
+                0xf8, 0x68, 0x03, 0x48, 0x2c, 0xb3, 0x7e, 0xd6, 0xd5, 0xc0, 0xbb, 0x8d, 0x50, 0xcf,
+                0x1f, 0x50, 0xd4, 0x76, 0xaa, 0x04, 0x58, 0xbd, 0xab, 0xa8, 0x06, 0xf4, 0x8b, 0xe9,
+                0xdc, 0xb8,
+            ]
+        );
+    }
+
+    /// CAVP HMAC_DRBG vector (SHA-256, no reseed, `AdditionalInputLen = 256`,
+    /// COUNT = 0)
+    #[test]
+    fn cavp_additional_input() {
+        let entropy_input = [
+            0xf9, 0x7a, 0x3c, 0xfd, 0x91, 0xfa, 0xa0, 0x46, 0xb9, 0xe6, 0x1b, 0x94, 0x93, 0xd4,
+            0x36, 0xc4, 0x93, 0x1f, 0x60, 0x4b, 0x22, 0xf1, 0x08, 0x15, 0x21, 0xb3, 0x41, 0x91,
+            0x51, 0xe8, 0xff, 0x06, 0x11, 0xf3, 0xa7, 0xd4, 0x35, 0x95, 0x35, 0x7d, 0x58, 0x12,
+            0x0b, 0xd1, 0xe2, 0xdd, 0x8a, 0xed,
+        ];
+        let mut rng = HmacDrbgSha256::new(&entropy_input, &[]);
+        let mut buf = [0; 128];
+        rng.generate(
+            &mut buf,
+            &[
+                0x51, 0x72, 0x89, 0xaf, 0xe4, 0x44, 0xa0, 0xfe, 0x5e, 0xd1, 0xa4, 0x1d, 0xbb, 0xb5,
+                0xeb, 0x17, 0x15, 0x00, 0x79, 0xbd, 0xd3, 0x1e, 0x29, 0xcf, 0x2f, 0xf3, 0x00, 0x34,
+                0xd8, 0x26, 0x8e, 0x3b,
+            ],
+        )
+        .unwrap();
+        rng.generate(
+            &mut buf,
+            &[
+                0x88, 0x02, 0x8d, 0x29, 0xef, 0x80, 0xb4, 0xe6, 0xf0, 0xfe, 0x12, 0xf9, 0x1d, 0x74,
+                0x49, 0xfe, 0x75, 0x06, 0x26, 0x82, 0xe8, 0x9c, 0x57, 0x14, 0x40, 0xc0, 0xc9, 0xb5,
+                0x2c, 0x42, 0xa6, 0xe0,
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            buf,
+            [
+                0xc6, 0x87, 0x1c, 0xff, 0x08, 0x24, 0xfe, 0x55, 0xea, 0x76, 0x89, 0xa5, 0x22, 0x29,
+                0x88, 0x67, 0x30, 0x45, 0x0e, 0x5d, 0x36, 0x2d, 0xa5, 0xbf, 0x59, 0x0d, 0xcf, 0x9a,
+                0xcd, 0x67, 0xfe, 0xd4, 0xcb, 0x32, 0x10, 0x7d, 0xf5, 0xd0, 0x39, 0x69, 0xa6, 0x6b,
+                0x1f, 0x64, 0x94, 0xfd, 0xf5, 0xd6, 0x3d, 0x5b, 0x4d, 0x0d, 0x34, 0xea, 0x73, 0x99,
+                0xa0, 0x7d, 0x01, 0x16, 0x12, 0x6d, 0x0d, 0x51, 0x8c, 0x7c, 0x55, 0xba, 0x46, 0xe1,
+                0x2f, 0x62, 0xef, 0xc8, 0xfe, 0x28, 0xa5, 0x1c, 0x9d, 0x42, 0x8e, 0x6d, 0x37, 0x1d,
+                0x73, 0x97, 0xab, 0x31, 0x9f, 0xc7, 0x3d, 0xed, 0x47, 0x22, 0xe5, 0xb4, 0xf3, 0x00,
+                0x04, 0x03, 0x2a, 0x61, 0x28, 0xdf, 0x5e, 0x74, 0x97, 0xec, 0xf8, 0x2c, 0xa7, 0xb0,
+                0xa5, 0x0e, 0x86, 0x7e, 0xf6, 0x72, 0x8a, 0x4f, 0x50, 0x9a, 0x8c, 0x85, 0x90, 0x87,
+                0x03, 0x9c,
+            ]
+        );
+    }
+
+    /// CAVP HMAC_DRBG vector (SHA-256, no reseed,
+    /// `PersonalizationStringLen = 256`, COUNT = 0)
+    #[test]
+    fn cavp_personalization_string() {
+        let entropy_input = [
+            0x8d, 0xf0, 0x13, 0xb4, 0xd1, 0x03, 0x52, 0x30, 0x73, 0x91, 0x7d, 0xdf, 0x6a, 0x86,
+            0x97, 0x93, 0x05, 0x9e, 0x99, 0x43, 0xfc, 0x86, 0x54, 0x54, 0x9e, 0x7a, 0xb2, 0x2f,
+            0x7c, 0x29, 0xf1, 0x22, 0xda, 0x26, 0x25, 0xaf, 0x2d, 0xdd, 0x4a, 0xbc, 0xce, 0x3c,
+            0xf4, 0xfa, 0x46, 0x59, 0xd8, 0x4e,
+        ];
+        let mut rng = HmacDrbgSha256::new(
+            &entropy_input,
+            &[
+                0xb5, 0x71, 0xe6, 0x6d, 0x7c, 0x33, 0x8b, 0xc0, 0x7b, 0x76, 0xad, 0x37, 0x57, 0xbb,
+                0x2f, 0x94, 0x52, 0xbf, 0x7e, 0x07, 0x43, 0x7a, 0xe8, 0x58, 0x1c, 0xe7, 0xbc, 0x7c,
+                0x3a, 0xc6, 0x51, 0xa9,
+            ],
+        );
+        let mut buf = [0; 128];
+        rng.generate(&mut buf, &[]).unwrap();
+        rng.generate(&mut buf, &[]).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0xb9, 0x1c, 0xba, 0x4c, 0xc8, 0x4f, 0xa2, 0x5d, 0xf8, 0x61, 0x0b, 0x81, 0xb6, 0x41,
+                0x40, 0x27, 0x68, 0xa2, 0x09, 0x72, 0x34, 0x93, 0x2e, 0x37, 0xd5, 0x90, 0xb1, 0x15,
+                0x4c, 0xbd, 0x23, 0xf9, 0x74, 0x52, 0xe3, 0x10, 0xe2, 0x91, 0xc4, 0x51, 0x46, 0x14,
+                0x7f, 0x0d, 0xa2, 0xd8, 0x17, 0x61, 0xfe, 0x90, 0xfb, 0xa6, 0x4f, 0x94, 0x41, 0x9c,
+                0x0f, 0x66, 0x2b, 0x28, 0xc1, 0xed, 0x94, 0xda, 0x48, 0x7b, 0xb7, 0xe7, 0x3e, 0xec,
+                0x79, 0x8f, 0xbc, 0xf9, 0x81, 0xb7, 0x91, 0xd1, 0xbe, 0x4f, 0x17, 0x7a, 0x89, 0x07,
+                0xaa, 0x3c, 0x40, 0x16, 0x43, 0xa5, 0xb6, 0x2b, 0x87, 0xb8, 0x9d, 0x66, 0xb3, 0xa6,
+                0x0e, 0x40, 0xd4, 0xa8, 0xe4, 0xe9, 0xd8, 0x2a, 0xf6, 0xd2, 0x70, 0x0e, 0x6f, 0x53,
+                0x5c, 0xdb, 0x51, 0xf7, 0x5c, 0x32, 0x17, 0x29, 0x10, 0x37, 0x41, 0x03, 0x0c, 0xcc,
+                0x3a, 0x56,
+            ]
+        );
+    }
+
+    #[test]
+    fn reseed() {
+        let mut rng = HmacDrbgSha256::from_seed(ENTROPY_INPUT.into());
+        rng.reseed(&range::<32>(0x80), &range::<32>(0x60));
+        let mut buf = [0; 128];
+        rng.generate(&mut buf, &[]).unwrap();
+        rng.generate(&mut buf, &range::<32>(0xa0)).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x56, 0xc0, 0x7b, 0x3c, 0x3f, 0x24, 0x96, 0xaf, 0x01, 0x4d, 0x47, 0x16, 0x70, 0x13,
+                0x69, 0x2f, 0x33, 0xfd, 0x1a, 0xc3, 0xd4, 0x32, 0x6b, 0xda, 0x0c, 0x53, 0xa9, 0xeb,
+                0x08, 0xd7, 0xe6, 0x0a, 0xa1, 0xd8, 0x85, 0x24, 0xf5, 0x3e, 0xb9, 0xbc, 0xb0, 0x18,
+                0x3c, 0x24, 0xb9, 0xa1, 0x7f, 0x58, 0xe1, 0xff, 0xc3, 0x21, 0x91, 0xf3, 0x15, 0xd7,
+                0x8c, 0xc7, 0x14, 0x47, 0x5b, 0x11, 0x19, 0x02, 0x4a, 0xfa, 0x27, 0xd3, 0x63, 0xba,
+                0x11, 0xfd, 0x53, 0x82, 0x36, 0xdb, 0x9b, 0x64, 0xa3, 0xba, 0xd9, 0x9e, 0x04, 0x1e,
+                0x1e, 0xd9, 0x45, 0x08, 0xa0, 0xd8, 0x89, 0xd0, 0xb5, 0x9a, 0x32, 0x73, 0x2e, 0xaf,
+                0x75, 0x77, 0xf2, 0x22, 0x81, 0x40, 0x00, 0xc5, 0x7a, 0x79, 0x64, 0xed, 0x04, 0x2c,
+                0xd8, 0x51, 0xb9, 0xaf, 0x25, 0x85, 0xa9, 0xc9, 0x43, 0xb8, 0x43, 0xc3, 0xd2, 0x2f,
+                0x06, 0x8a,
+            ]
+        );
+    }
+
+    /// CAVP HMAC_DRBG vector (SHA-512, no reseed, COUNT = 0)
+    #[test]
+    fn cavp_sha512() {
+        let entropy_input = [
+            0x35, 0x04, 0x9f, 0x38, 0x9a, 0x33, 0xc0, 0xec, 0xb1, 0x29, 0x32, 0x38, 0xfd, 0x95,
+            0x1f, 0x8f, 0xfd, 0x51, 0x7d, 0xfd, 0xe0, 0x60, 0x41, 0xd3, 0x29, 0x45, 0xb3, 0xe2,
+            0x69, 0x14, 0xba, 0x15, 0xf7, 0x32, 0x87, 0x60, 0xbe, 0x61, 0x68, 0xe6, 0xaa, 0x9f,
+            0xb5, 0x47, 0x84, 0x98, 0x9a, 0x11,
+        ];
+        let mut rng = HmacDrbgSha512::new(&entropy_input, &[]);
+        let mut buf = [0; 256];
+        rng.generate(&mut buf, &[]).unwrap();
+        rng.generate(&mut buf, &[]).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0xe7, 0x64, 0x91, 0xb0, 0x26, 0x0a, 0xac, 0xfd, 0xed, 0x01, 0xad, 0x39, 0xfb, 0xf1,
+                0xa6, 0x6a, 0x88, 0x28, 0x4c, 0xaa, 0x51, 0x23, 0x36, 0x8a, 0x2a, 0xd9, 0x33, 0x0e,
+                0xe4, 0x83, 0x35, 0xe3, 0xc9, 0xc9, 0xba, 0x90, 0xe6, 0xcb, 0xc9, 0x42, 0x99, 0x62,
+                0xd6, 0x0c, 0x1a, 0x66, 0x61, 0xed, 0xcf, 0xaa, 0x31, 0xd9, 0x72, 0xb8, 0x26, 0x4b,
+                0x9d, 0x45, 0x62, 0xcf, 0x18, 0x49, 0x41, 0x28, 0xa0, 0x92, 0xc1, 0x7a, 0x8d, 0xa6,
+                0xf3, 0x11, 0x3e, 0x8a, 0x7e, 0xdf, 0xcd, 0x44, 0x27, 0x08, 0x2b, 0xd3, 0x90, 0x67,
+                0x5e, 0x96, 0x62, 0x40, 0x81, 0x44, 0x97, 0x17, 0x17, 0x30, 0x3d, 0x8d, 0xc3, 0x52,
+                0xc9, 0xe8, 0xb9, 0x5e, 0x7f, 0x35, 0xfa, 0x2a, 0xc9, 0xf5, 0x49, 0xb2, 0x92, 0xbc,
+                0x7c, 0x4b, 0xc7, 0xf0, 0x1e, 0xe0, 0xa5, 0x77, 0x85, 0x9e, 0xf6, 0xe8, 0x2d, 0x79,
+                0xef, 0x23, 0x89, 0x2d, 0x16, 0x7c, 0x14, 0x0d, 0x22, 0xaa, 0xc3, 0x2b, 0x64, 0xcc,
+                0xdf, 0xee, 0xe2, 0x73, 0x05, 0x28, 0xa3, 0x87, 0x63, 0xb2, 0x42, 0x27, 0xf9, 0x1a,
+                0xc3, 0xff, 0xe4, 0x7f, 0xb1, 0x15, 0x38, 0xe4, 0x35, 0x30, 0x7e, 0x77, 0x48, 0x18,
+                0x02, 0xb0, 0xf6, 0x13, 0xf3, 0x70, 0xff, 0xb0, 0xdb, 0xea, 0xb7, 0x74, 0xfe, 0x1e,
+                0xfb, 0xb1, 0xa8, 0x0d, 0x01, 0x15, 0x4a, 0x94, 0x59, 0xe7, 0x3a, 0xd3, 0x61, 0x10,
+                0x8b, 0xbc, 0x86, 0xb0, 0x91, 0x4f, 0x09, 0x51, 0x36, 0xcb, 0xe6, 0x34, 0x55, 0x5c,
+                0xe0, 0xbb, 0x26, 0x36, 0x18, 0xdc, 0x5c, 0x36, 0x72, 0x91, 0xce, 0x08, 0x25, 0x51,
+                0x89, 0x87, 0x15, 0x4f, 0xe9, 0xec, 0xb0, 0x52, 0xb3, 0xf0, 0xa2, 0x56, 0xfc, 0xc3,
+                0x0c, 0xc1, 0x45, 0x72, 0x53, 0x1c, 0x96, 0x28, 0x97, 0x36, 0x39, 0xbe, 0xda, 0x45,
+                0x6f, 0x2b, 0xdd, 0xf6,
+            ]
+        );
+    }
+
+    #[test]
+    fn sha512_personalization_and_additional_input() {
+        let mut rng = HmacDrbgSha512::new(&ENTROPY_INPUT, &range::<32>(0x40));
+        let mut buf = [0; 128];
+        rng.generate(&mut buf, &range::<32>(0x60)).unwrap();
+        rng.generate(&mut buf, &range::<32>(0xa0)).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x77, 0x1f, 0x9e, 0xfa, 0xed, 0xd0, 0xaf, 0x2a, 0x04, 0xbc, 0x38, 0x06, 0xc6, 0x73,
+                0xe1, 0xe8, 0x5f, 0xf9, 0xbd, 0xd6, 0xc3, 0x7c, 0xc9, 0x0f, 0x4f, 0xc1, 0x2d, 0x81,
+                0xad, 0xd0, 0xa2, 0x62, 0x0f, 0x2f, 0xbd, 0xb5, 0x2f, 0x05, 0x5d, 0x6c, 0x2d, 0x2b,
+                0x2d, 0x6e, 0x7a, 0xfb, 0x79, 0xca, 0xd1, 0xec, 0xc3, 0x56, 0x84, 0xbe, 0x56, 0xf7,
+                0xf8, 0xe5, 0x11, 0xe9, 0x27, 0x7a, 0x4d, 0x1a, 0xc1, 0x77, 0x37, 0x6a, 0xf0, 0x27,
+                0xe4, 0x03, 0x01, 0x15, 0xa9, 0xdf, 0x39, 0x14, 0xbf, 0x47, 0x53, 0xc5, 0xc9, 0x9c,
+                0xa6, 0x1b, 0xb9, 0xc4, 0xf3, 0x5d, 0xf2, 0x80, 0x26, 0xbe, 0x45, 0xef, 0x7a, 0x69,
+                0xa1, 0x97, 0x67, 0xbb, 0xaf, 0x68, 0x1c, 0x09, 0x05, 0xb0, 0x54, 0x64, 0x35, 0x59,
+                0x33, 0x80, 0x69, 0x52, 0xf8, 0xd5, 0x89, 0x41, 0xe8, 0x0c, 0x7d, 0x49, 0x53, 0x55,
+                0xe8, 0x60,
+            ]
+        );
+    }
+
+    #[test]
+    fn sha512_reseed() {
+        let mut rng = HmacDrbgSha512::from_seed(ENTROPY_INPUT.into());
+        rng.reseed(&range::<32>(0x80), &range::<32>(0x60));
+        let mut buf = [0; 128];
+        rng.generate(&mut buf, &[]).unwrap();
+        rng.generate(&mut buf, &range::<32>(0xa0)).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x5d, 0x14, 0x00, 0xae, 0xce, 0x22, 0x61, 0x46, 0xfa, 0xb4, 0xc6, 0xc7, 0x86, 0xfa,
+                0xc2, 0xd2, 0x26, 0xcf, 0xb7, 0x07, 0x0a, 0x1d, 0x2a, 0xdc, 0x35, 0xb6, 0x8b, 0x27,
+                0xa3, 0x56, 0xd3, 0x03, 0xac, 0x86, 0x22, 0x9e, 0x0f, 0x61, 0x7d, 0x9a, 0x01, 0x76,
+                0xa7, 0x58, 0xf1, 0x05, 0x79, 0x36, 0xd6, 0x4b, 0x78, 0xed, 0x62, 0x9d, 0x9e, 0x10,
+                0x4c, 0x30, 0x22, 0xef, 0xa7, 0xaa, 0x6e, 0x29, 0x95, 0xda, 0x48, 0xc3, 0x21, 0xe8,
+                0xec, 0xa2, 0x90, 0xbd, 0x1d, 0x4e, 0x1b, 0xe4, 0xe5, 0xe5, 0xb6, 0x95, 0x88, 0x80,
+                0x6d, 0x77, 0x57, 0x3c, 0x1c, 0xef, 0x08, 0x0d, 0x63, 0xdc, 0x0b, 0x6b, 0xdd, 0x2d,
+                0x9e, 0xc9, 0xaf, 0xd1, 0x4d, 0x33, 0xf0, 0x8c, 0xa8, 0x30, 0xf4, 0xc4, 0x0d, 0xc0,
+                0x99, 0x5b, 0x66, 0x22, 0x12, 0xdb, 0xcc, 0x98, 0x54, 0x42, 0xe2, 0xc6, 0x93, 0xd9,
+                0x34, 0xc7,
+            ]
+        );
+    }
+
+    #[test]
+    fn request_limits() {
+        let mut rng = HmacDrbgSha256::from_seed(ENTROPY_INPUT.into());
+        let mut buf = [0; MAX_BYTES_PER_REQUEST + 1];
+        assert_eq!(rng.generate(&mut buf, &[]), Err(Error::RequestTooLarge));
+        assert!(rng.try_fill_bytes(&mut buf).is_ok());
+
+        rng.reseed_counter = RESEED_INTERVAL + 1;
+        assert_eq!(
+            rng.generate(&mut buf[..16], &[]),
+            Err(Error::ReseedRequired)
+        );
+        rng.reseed(&ENTROPY_INPUT, &[]);
+        assert!(rng.generate(&mut buf[..16], &[]).is_ok());
+    }
+}
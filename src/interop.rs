@@ -0,0 +1,94 @@
+//! Conversions between the seeds and the array types of other crates.
+
+#[cfg(feature = "generic-array")]
+use generic_array::{
+    typenum::{U32, U40, U48},
+    GenericArray,
+};
+#[cfg(feature = "hybrid-array")]
+use hybrid_array::{
+    sizes::{U32 as HybridU32, U40 as HybridU40, U48 as HybridU48},
+    Array,
+};
+
+use crate::{Aes128Seed, Aes192Seed, Seed};
+
+#[cfg(feature = "generic-array")]
+macro_rules! impl_generic_array {
+    ($name:ident, $size:ty) => {
+        impl From<GenericArray<u8, $size>> for $name {
+            fn from(value: GenericArray<u8, $size>) -> Self {
+                let mut seed = Self::default();
+                seed.0.copy_from_slice(&value);
+                seed
+            }
+        }
+
+        impl From<$name> for GenericArray<u8, $size> {
+            fn from(value: $name) -> Self {
+                GenericArray::clone_from_slice(&value.0)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "generic-array")]
+impl_generic_array!(Seed, U48);
+#[cfg(feature = "generic-array")]
+impl_generic_array!(Aes128Seed, U32);
+#[cfg(feature = "generic-array")]
+impl_generic_array!(Aes192Seed, U40);
+
+#[cfg(feature = "hybrid-array")]
+macro_rules! impl_hybrid_array {
+    ($name:ident, $size:ty) => {
+        impl From<Array<u8, $size>> for $name {
+            fn from(value: Array<u8, $size>) -> Self {
+                Self(value.into())
+            }
+        }
+
+        impl From<$name> for Array<u8, $size> {
+            fn from(value: $name) -> Self {
+                Array::from(value.0)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "hybrid-array")]
+impl_hybrid_array!(Seed, HybridU48);
+#[cfg(feature = "hybrid-array")]
+impl_hybrid_array!(Aes128Seed, HybridU32);
+#[cfg(feature = "hybrid-array")]
+impl_hybrid_array!(Aes192Seed, HybridU40);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "generic-array")]
+    #[test]
+    fn generic_array() {
+        let bytes: [u8; 48] = core::array::from_fn(|i| i as u8);
+        let array = GenericArray::<u8, U48>::clone_from_slice(&bytes);
+        let seed = Seed::from(array);
+        assert_eq!(seed[..], bytes[..]);
+        assert_eq!(GenericArray::<u8, U48>::from(seed), array);
+
+        let seed = Aes128Seed::from(GenericArray::<u8, U32>::default());
+        assert_eq!(seed[..], [0; 32]);
+    }
+
+    #[cfg(feature = "hybrid-array")]
+    #[test]
+    fn hybrid_array() {
+        let bytes: [u8; 48] = core::array::from_fn(|i| i as u8);
+        let seed = Seed::from(Array::<u8, HybridU48>::from(bytes));
+        assert_eq!(seed[..], bytes[..]);
+        assert_eq!(Array::<u8, HybridU48>::from(seed).0, bytes);
+
+        let seed = Aes192Seed::from(Array::<u8, HybridU40>::default());
+        assert_eq!(seed[..], [0; 40]);
+    }
+}
@@ -0,0 +1,629 @@
+//! Reproducing the KATs of the NIST PQC submission packages.
+//!
+//! `PQCgenKAT_kem.c` seeds a master RNG with the fixed entropy input
+//! `0x00, 0x01, ..., 0x2F`, draws one seed per count from it and re-seeds the
+//! RNG with that seed before running the scheme. [standard_kat_rngs] yields
+//! the RNGs for all counts:
+//! ```
+//! use nist_pqc_seeded_rng::{kat::standard_kat_rngs, RngCore};
+//!
+//! for (count, mut rng) in standard_kat_rngs() {
+//!     // run key generation, encapsulation, ... of the KAT for `count` with `rng`
+//!     let coins = rng.next_u64();
+//! }
+//! ```
+//!
+//! To investigate a single count, [kat_seed] returns its seed as found in the
+//! KAT files. With the `kat-seeds` feature, the seeds of all counts are
+//! additionally available as precomputed table [KAT_SEEDS], which requires no
+//! invocation of AES.
+//!
+//! With the `std` feature, [KatWriter] and [SignKatWriter] produce `.req` and
+//! `.rsp` files in the format of `PQCgenKAT_kem.c` and `PQCgenKAT_sign.c`,
+//! respectively.
+//!
+//! Note that `PQCgenKAT_sign.c` draws the message of each count from the
+//! master RNG right after the seed. Hence, only the seed of the first count of
+//! signature schemes agrees with [kat_seed].
+
+use core::iter::FusedIterator;
+#[cfg(feature = "std")]
+use std::{io, string::String};
+
+use rand_core::SeedableRng;
+
+#[cfg(feature = "kat-seeds")]
+use crate::seed;
+use crate::{NistPqcAes256CtrRng, Seed, SEED_LENGTH};
+
+/// Number of counts in the KAT files of the submission packages.
+pub const COUNTS: usize = 100;
+
+/// Entropy input of the master RNG, i.e., the bytes `0x00, 0x01, ..., 0x2F`.
+pub const ENTROPY_INPUT: [u8; 48] = {
+    let mut entropy_input = [0; SEED_LENGTH];
+    let mut i = 0;
+    while i < SEED_LENGTH {
+        entropy_input[i] = i as u8;
+        i += 1;
+    }
+    entropy_input
+};
+
+/// Iterator over the RNGs of the standard KAT counts
+///
+/// Created by [standard_kat_rngs].
+#[derive(Debug)]
+pub struct StandardKatRngs {
+    master: NistPqcAes256CtrRng,
+    count: usize,
+}
+
+/// Return an iterator over the [COUNTS] counts and their RNGs.
+///
+/// The RNGs are seeded exactly as in `PQCgenKAT_kem.c`, i.e., the `n`-th RNG
+/// is instantiated from the `n`-th request of 48 bytes to the master RNG
+/// seeded with the standard entropy input.
+pub fn standard_kat_rngs() -> StandardKatRngs {
+    StandardKatRngs {
+        master: NistPqcAes256CtrRng::from_seed(Seed::new(ENTROPY_INPUT)),
+        count: 0,
+    }
+}
+
+/// Return the seed of the KAT count `count`.
+///
+/// The seed is the `count`-th request of 48 bytes to the master RNG seeded
+/// with the standard entropy input, hence computing it requires `count + 1`
+/// requests. Seeds of counts beyond [COUNTS] continue the stream of the
+/// master RNG. With the `kat-seeds` feature, the seeds of the first [COUNTS]
+/// counts are taken from [KAT_SEEDS].
+/// ```
+/// use nist_pqc_seeded_rng::kat::kat_seed;
+///
+/// assert_eq!(
+///     kat_seed(1).to_string(),
+///     "D81C4D8D734FCBFBEADE3D3F8A039FAA2A2C9957E835AD55B22E75BF57BB556AC81ADDE6AEEB4A5A875C3BFCADFA958F"
+/// );
+/// ```
+pub fn kat_seed(count: usize) -> Seed {
+    #[cfg(feature = "kat-seeds")]
+    if count < COUNTS {
+        return KAT_SEEDS[count].clone();
+    }
+
+    let mut master = NistPqcAes256CtrRng::from_seed(Seed::new(ENTROPY_INPUT));
+    for _ in 0..count {
+        Seed::generate(&mut master);
+    }
+    Seed::generate(&mut master)
+}
+
+impl Iterator for StandardKatRngs {
+    type Item = (usize, NistPqcAes256CtrRng);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count == COUNTS {
+            return None;
+        }
+        let count = self.count;
+        self.count += 1;
+        Some((
+            count,
+            NistPqcAes256CtrRng::from_seed(Seed::generate(&mut self.master)),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = COUNTS - self.count;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for StandardKatRngs {}
+
+impl FusedIterator for StandardKatRngs {}
+
+/// Values of a KAT count of a KEM
+///
+/// The fields correspond to the entries of the `.rsp` files.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug)]
+pub struct KemRecord<'a> {
+    /// The count
+    pub count: usize,
+    /// The seed of the RNG of the count
+    pub seed: &'a Seed,
+    /// The public key
+    pub pk: &'a [u8],
+    /// The secret key
+    pub sk: &'a [u8],
+    /// The ciphertext
+    pub ct: &'a [u8],
+    /// The shared secret
+    pub ss: &'a [u8],
+}
+
+/// Writer of KAT files of KEMs
+///
+/// The output matches `PQCgenKAT_kem.c` byte for byte, i.e., the fields are
+/// written in the same order with uppercase hex encoding and every record is
+/// terminated by a blank line:
+/// ```
+/// use nist_pqc_seeded_rng::{
+///     kat::{KatWriter, KemRecord, ENTROPY_INPUT},
+///     NistPqcAes256CtrRng, RngCore, SeedableRng, SubSeeds,
+/// };
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut req = KatWriter::new(Vec::new());
+/// let mut rsp = KatWriter::new(Vec::new());
+/// rsp.write_header("Toy-KEM")?;
+///
+/// let mut master = NistPqcAes256CtrRng::from(ENTROPY_INPUT);
+/// for (count, seed) in SubSeeds::new(&mut master).take(2).enumerate() {
+///     req.write_request(count, &seed)?;
+///
+///     let mut rng = NistPqcAes256CtrRng::from_seed(seed.clone());
+///     let mut sk = [0; 16];
+///     rng.fill_bytes(&mut sk);
+///     // ... run key generation, encapsulation and decapsulation with `rng`
+///     rsp.write_response(&KemRecord {
+///         count,
+///         seed: &seed,
+///         pk: &[1, 2],
+///         sk: &sk,
+///         ct: &[3, 4],
+///         ss: &[5, 6],
+///     })?;
+/// }
+///
+/// let rsp = String::from_utf8(rsp.into_inner()).unwrap();
+/// assert!(rsp.starts_with("# Toy-KEM\n\ncount = 0\nseed = 061550234D"));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The writer issues many small writes, hence wrap files in a
+/// [BufWriter](std::io::BufWriter).
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct KatWriter<W: io::Write> {
+    writer: W,
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> KatWriter<W> {
+    /// Write KAT files to `writer`.
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Return the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Write the header of the `.rsp` file naming the algorithm.
+    ///
+    /// This corresponds to `CRYPTO_ALGNAME` in the submission packages.
+    pub fn write_header(&mut self, algorithm_name: &str) -> io::Result<()> {
+        write!(self.writer, "# {}\n\n", algorithm_name)
+    }
+
+    /// Write the record of `count` of the `.req` file.
+    ///
+    /// Besides the count and the seed, all fields are empty.
+    pub fn write_request(&mut self, count: usize, seed: &Seed) -> io::Result<()> {
+        writeln!(self.writer, "count = {}", count)?;
+        writeln!(self.writer, "seed = {}", seed)?;
+        write!(self.writer, "pk =\nsk =\nct =\nss =\n\n")
+    }
+
+    /// Write the record of the `.rsp` file.
+    pub fn write_response(&mut self, record: &KemRecord<'_>) -> io::Result<()> {
+        writeln!(self.writer, "count = {}", record.count)?;
+        writeln!(self.writer, "seed = {}", record.seed)?;
+        write_bytes(&mut self.writer, "pk", record.pk)?;
+        write_bytes(&mut self.writer, "sk", record.sk)?;
+        write_bytes(&mut self.writer, "ct", record.ct)?;
+        write_bytes(&mut self.writer, "ss", record.ss)?;
+        writeln!(self.writer)
+    }
+}
+
+/// Write the field `name` as in `fprintBstr` of the submission packages.
+#[cfg(feature = "std")]
+fn write_bytes(writer: &mut impl io::Write, name: &str, bytes: &[u8]) -> io::Result<()> {
+    // `fprintBstr` prints `00` for empty values
+    if bytes.is_empty() {
+        return writeln!(writer, "{} = 00", name);
+    }
+
+    let mut hex = String::with_capacity(2 * bytes.len());
+    crate::hex::encode(&mut hex, bytes, true).expect("writing to a string does not fail");
+    writeln!(writer, "{} = {}", name, hex)
+}
+
+/// Values of a KAT count of a signature scheme
+///
+/// The fields correspond to the entries of the `.rsp` files. The lengths
+/// `mlen` and `smlen` are those of `msg` and `sm`.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug)]
+pub struct SignRecord<'a> {
+    /// The count
+    pub count: usize,
+    /// The seed of the RNG of the count
+    pub seed: &'a Seed,
+    /// The message
+    pub msg: &'a [u8],
+    /// The public key
+    pub pk: &'a [u8],
+    /// The secret key
+    pub sk: &'a [u8],
+    /// The signed message
+    pub sm: &'a [u8],
+}
+
+/// Writer of KAT files of signature schemes
+///
+/// The output matches `PQCgenKAT_sign.c` byte for byte. The seed and the
+/// message of `33 * (count + 1)` bytes of each count are drawn from the master
+/// RNG in turn:
+/// ```
+/// use nist_pqc_seeded_rng::{
+///     kat::{SignKatWriter, SignRecord, ENTROPY_INPUT},
+///     NistPqcAes256CtrRng, RngCore, Seed, SeedableRng,
+/// };
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut req = SignKatWriter::new(Vec::new());
+/// let mut rsp = SignKatWriter::new(Vec::new());
+/// rsp.write_header("Toy-Sign")?;
+///
+/// let mut master = NistPqcAes256CtrRng::from(ENTROPY_INPUT);
+/// for count in 0..2 {
+///     let seed = Seed::generate(&mut master);
+///     let mut msg = vec![0; 33 * (count + 1)];
+///     master.fill_bytes(&mut msg);
+///     req.write_request(count, &seed, &msg)?;
+///
+///     let mut rng = NistPqcAes256CtrRng::from_seed(seed.clone());
+///     let mut sk = [0; 16];
+///     rng.fill_bytes(&mut sk);
+///     // ... run key generation and signing with `rng`
+///     let sm = [&[7; 8], msg.as_slice()].concat();
+///     rsp.write_response(&SignRecord {
+///         count,
+///         seed: &seed,
+///         msg: &msg,
+///         pk: &[1, 2],
+///         sk: &sk,
+///         sm: &sm,
+///     })?;
+/// }
+///
+/// let rsp = String::from_utf8(rsp.into_inner()).unwrap();
+/// assert!(rsp.starts_with("# Toy-Sign\n\ncount = 0\nseed = 061550234D"));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The writer issues many small writes, hence wrap files in a
+/// [BufWriter](std::io::BufWriter).
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct SignKatWriter<W: io::Write> {
+    writer: W,
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> SignKatWriter<W> {
+    /// Write KAT files to `writer`.
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Return the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Write the header of the `.rsp` file naming the algorithm.
+    ///
+    /// This corresponds to `CRYPTO_ALGNAME` in the submission packages.
+    pub fn write_header(&mut self, algorithm_name: &str) -> io::Result<()> {
+        write!(self.writer, "# {}\n\n", algorithm_name)
+    }
+
+    /// Write the record of `count` of the `.req` file.
+    ///
+    /// Besides the count, the seed and the message, all fields are empty.
+    pub fn write_request(&mut self, count: usize, seed: &Seed, msg: &[u8]) -> io::Result<()> {
+        writeln!(self.writer, "count = {}", count)?;
+        writeln!(self.writer, "seed = {}", seed)?;
+        writeln!(self.writer, "mlen = {}", msg.len())?;
+        write_bytes(&mut self.writer, "msg", msg)?;
+        write!(self.writer, "pk =\nsk =\nsmlen =\nsm =\n\n")
+    }
+
+    /// Write the record of the `.rsp` file.
+    pub fn write_response(&mut self, record: &SignRecord<'_>) -> io::Result<()> {
+        writeln!(self.writer, "count = {}", record.count)?;
+        writeln!(self.writer, "seed = {}", record.seed)?;
+        writeln!(self.writer, "mlen = {}", record.msg.len())?;
+        write_bytes(&mut self.writer, "msg", record.msg)?;
+        write_bytes(&mut self.writer, "pk", record.pk)?;
+        write_bytes(&mut self.writer, "sk", record.sk)?;
+        writeln!(self.writer, "smlen = {}", record.sm.len())?;
+        write_bytes(&mut self.writer, "sm", record.sm)?;
+        writeln!(self.writer)
+    }
+}
+
+/// Seeds of the [COUNTS] standard KAT counts
+///
+/// The `n`-th entry is the seed of count `n` as returned by [kat_seed].
+#[cfg(feature = "kat-seeds")]
+pub const KAT_SEEDS: [Seed; COUNTS] = [
+    seed!("061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7056A8C266F9EF97ED08541DBD2E1FFA1"),
+    seed!("D81C4D8D734FCBFBEADE3D3F8A039FAA2A2C9957E835AD55B22E75BF57BB556AC81ADDE6AEEB4A5A875C3BFCADFA958F"),
+    seed!("64335BF29E5DE62842C941766BA129B0643B5E7121CA26CFC190EC7DC3543830557FDD5C03CF123A456D48EFEA43C868"),
+    seed!("225D5CE2CEAC61930A07503FB59F7C2F936A3E075481DA3CA299A80F8C5DF9223A073E7B90E02EBF98CA2227EBA38C1A"),
+    seed!("EDC76E7C1523E3862552133FEA4D2AB05C69FB54A9354F0846456A2A407E071DF4650EC0E0A5666A52CD09462DBC51F9"),
+    seed!("AA93649193C2C5985ACF8F9E6AC50C36AE16A2526D7C684F7A3BB4ABCD7B6FF790E82BADCE89BC7380D66251F97AAAAA"),
+    seed!("2E014DC7C2696B9F6D4AF555CBA4B931B34863FF60E2341D4FDFE472FEF2FE2C33E0813FC5CAFDE4E30277FE522A9049"),
+    seed!("AEFB28FDD34E0AB403A703B535296E3A545CA479C1D8148E2D501B3C8DD8B1034BD986F13F1A7B4671BE769359FD2AAB"),
+    seed!("CBE5161E8DE02DDA7DE204AEB0FBB4CA81344BA8C30FE357A4664E5D2988A03B64184D7DC69F8D367550E5FEA0876D41"),
+    seed!("B4663A7A9883386A2AE4CBD93787E247BF26087E3826D1B8DBEB679E49C0BB286E114F0E9F42F61F63DEC42B4F974846"),
+    seed!("980D0BA7C8F8B23D0E948A6029FF2659810EA1360064663A8994D0333C8543EE5FF5D6D5C9ACF446E61DC464F792B9D3"),
+    seed!("6C029462CA42ED520F10A579F52687101105E0B90C6E7BFA582A4C112B579D5AD0A0ABD38F72ABCFDCAAF5893A112BDC"),
+    seed!("DB00120937570D62331F4C3F19A10465231EFF46465CDEE336A0D46AA1E7493DF80F18617F9FFD0476CF7784A403EF4F"),
+    seed!("BD26C0B9A33E3B9B4C5D7EA32D5BD1FC371015BE163C86F584E49BFD5362C8D8341161CD1308115B2A03B7E5EADDD418"),
+    seed!("E2819EF86853BCA1B9DEE7EE1C1619988964F9A913E635AACF0D96CA6E0300D084329DABD8F149E24176D22757404260"),
+    seed!("669C4EF8A051CE201DA65FC4BC34D398EC1F806276FC5D987AD71D93BC12DC8F107B58BE6E8422A0795C88CB9A0E7488"),
+    seed!("9DEBCCFE818F6B5204DB4EA09C03EC9A19DCF1629C1527685B8A29776BB1DAAEC45F8ABF8F0ADC9A8C8BD6E2DF6D8048"),
+    seed!("8098AE7A92C10F707D405F7DEA02C2EFBEF44EFA132BA8AEFE81BD45E543ECEC74F10920AE48A40B0653D63532517F2A"),
+    seed!("D5F23808871544E9C1D6EACE2028362B48E225312F77663E9F78CAFEB512B908CD9E25875D61A16EC615F4B8FF826856"),
+    seed!("822CB47BE2266E182F34546924D753A5E3369011047E6950B00BC392F8FEC19EA87C26D8021D377DF86DC76C24C5F827"),
+    seed!("81401DB81138D6874E91B7C11D59596E4ACE543F5A3471B6FB00999221765FEC3CA057ABE20F03B2D59003375FD71FE8"),
+    seed!("30B5DE5B73681EC08AAA03F6F2D2169525D25F4042A5E3695A20A52CA54927B85F8BB948FC21DF7DEFC3910B28674994"),
+    seed!("E335DF8FC0D890588C3E305AC92C7160FF199E07C85760A828933750E3FED8C83B0DBE802234481ECF890A32D7A2884F"),
+    seed!("FBEA1BC2C379F4F8FDCB0DE260D31CDB064C9EA9B1D6DFBE91B3692ADD1D34DEC9C9FFAE7BF5E72ED2743BA3F9F2E43D"),
+    seed!("7E87FB886BC3C7C9FC12569F465D2ECD12532E76CC27C65644C8D3DD603B0CB2D036C5974E675058F271D5C82AD7A813"),
+    seed!("AD1424E804F306C7FF513DA4C1E8D445AFCA7BC942FAC5C0B335733AAF70693712ECBDE26EA726EE0F9FD9D52A83B1A4"),
+    seed!("7C33CA0E987226C8524DD56C811FA4D1CCF9995B1E4E4DD5B1481974E88CFABFBF6787775C2611CEFB27ED4403EA9B46"),
+    seed!("54770EA1252EA2857D6635151194F5F520ADEA8A41E409FF498D40C271359858FE2B084D5B96BEE087B8E8F4DD4E00C5"),
+    seed!("CD6CFE94E9C0A1CC4FFDCD2D7876504BE5F50F1D1CA5CF93482943465B268276056F2781F4DE805C138976CA72621387"),
+    seed!("265EB2DE7099E4BD5614E5DE7F0C2A05C78EF3E8E2DD4AE4CB70F3E5E59C8D1D88248303F07DE0C5508652DA66B47222"),
+    seed!("806BBD111F27C2668318387BD0830F65EC21A51AF01985EF48D03D64E1958FF7EE5133A4EBF6DBF36329BCAAF65F40EA"),
+    seed!("AD540A9CE816D6FB1661E5483C44F6FDD00C9E7BD1A8CEDA4B4C4D3697D4F78ED0A56954996CCB7DA96ECB8F5CB15809"),
+    seed!("288A5F2684D862A86D2790AFDDDDBAC6FDA934EE7D2E6DA1508BB550838609E8107312B28E00A6C01706374CCD3AEFA7"),
+    seed!("4635DC5BB92EF98CDB6220DF0DD717C7F8158375EAA2B78FC3F0B58E9C9653E92684CAD3461D9158A481DA3D14694C44"),
+    seed!("5DA2C51B4ACF488C8BDED5E985CC4702E4A7BCB248B5AC18AABA529F7F9CBF30EFA776E99F76D5C1686E94F50FB57DAE"),
+    seed!("4D2239E84B052109A78DBAB6D80C51A86D38248105970476B74A0B78B9CFAB6283E30D5A406FAE1C7F54F8BAE1110EE4"),
+    seed!("EE762F5C9021C36446706A88EF16312F4A12C725CD7AFFF1484337C91EDA8E89F7007F3705747D29907B3FB7500E5074"),
+    seed!("D882BA69AC8BBC88715F1C6387531F53273A5DAB87E66FAA8221A7F628D2BDEEE1CBC59C0E08D0ADD84520A3A70C1389"),
+    seed!("6C3AFF39F5D097096D882F24717718C8A702382DC4AAFFD7629763FDA73C163CF084807BBB0C9F600CD31A7135F48AEC"),
+    seed!("CF520B92A2E3677AFD003EC1EC6EF136A709D78F828C9C0DD4946EFBD451C5FAABFC83CA66F9D3D17EE4220553B7A69F"),
+    seed!("197E5D562DE7E01BED4FC597DB28DC6EFDF0179F3A5BDA5F94CAA39D67BAE730540534D59A7A06C8448F628DA8B7859F"),
+    seed!("F170583CB451D8A45D105457C02C01A33A40350616ED8515BD49067142F61EFB00F07857E4FFF3FE11E7164C648C76ED"),
+    seed!("44A6774B2CAC02DFF210FF861A090561A453DB311F47B6FEDB81811872D5D9489F5FC4103010139AE53FCAED209DC9BE"),
+    seed!("49E1855588B6235DF2A400C4A70AEDF8AB17B6E5E2891AA745F132FA2E7AB0C8117C1DF37C39F5D57624EB77C2B4A091"),
+    seed!("DF0E41D2F6F86C1F79D31FD5878E7AB434FC0AF3A0D5F47D2AB3FEF31A42BD949B0E3629DF9F575BEFBB62E829E51DAE"),
+    seed!("D3C9EBBA6EB03CCB5C9B9D2C8D7F0CFBBF50841E24396CDDF0E56525B38918C2FBE6C34CC1B93F7BCD4F4D5777E1A488"),
+    seed!("6B3996E8BC6F52879F2B7BE012C44AD555707CB7E5FD8ABB3457A298336D6FDC9EB7853008FF13201D5969A315C7E493"),
+    seed!("730B65ECE22DE27D573CE3AEA7CB021C415DF210D228808D91D4F380070FFCB0778B683C71D4853DEB569C822765F2A3"),
+    seed!("5522A5A891A9A9B5514F4556AFD8DF40B9CEC63A01492F0CB8A1DB073A285A963E4A9FF2376C88662F7D8D241F8ACF17"),
+    seed!("1853E72329353B3F89AE6A1B1EF700DA8ED3C10D19F9E61EE9252E28EBB0E15802EE43083A12A0B7527088832605E3AB"),
+    seed!("027C3D5847ED4470931141104F25B19AE76117CBB64B224EE424FFB782E9A0E988839E0BDED0DF666FE8E5FCBB5DBC09"),
+    seed!("450751D4401737459C6D93E6C5F2FBCC4A3AF7CD7250CCF404BBB817A67BAB7B4C9D0EF4570BFE25CF919DA331C31D88"),
+    seed!("5DE720F2D152BF4E1F96A61E7AE5F1BED6B8548E32638C2CCEC9F43B87D1BB43DFCF334F0582984D27E440D519AB662F"),
+    seed!("D71729DCBB27D7CB39E9E905025D3E55C8602EFBCC483C9B866EBF82326157833169243C14550AD728BD1470F39C642E"),
+    seed!("A7C2C8EDB3601396BEB2DF0657EC82FD5780A2723581A9E03DEE1CDB018440439BB1142CAB0487C5D136E9AF46338AB7"),
+    seed!("467F6158CB86B724039FF18C47950AE5C49170163C910FC9A9B30141F86E9C06EBCEC91497BCD156D95758C9F0C6EF91"),
+    seed!("687C02DE1041ABAC7B2C1E6EC2A7C3375552ED5EDB10E3A8139C24CC76BDA44D719D8121A81D47A0B762B4E9EEB85235"),
+    seed!("4142237070C216BCBE245A39BD9220533C97651D84832B26727855AD994A0760C52B9319AD404693E4248B8C5FF324B3"),
+    seed!("BD334D7B7EB14E00E68863F2E5551A095F8AF10681C28353FD19B9A7E70B8BFE266840860609008A567ABC66316C77CE"),
+    seed!("A0264C58AB1F2CBCB212077FD378D340307ACCB31F1312137CF84E3D3135044D4EAE8BD38BC3E540A0C14D46458F6179"),
+    seed!("99A9CDBFC674AB3FF2C64CDED7D697A6E27A767434A47AFF7C3FBF3C6A22D6043D27868955286A13EFE3DE36D22EC48E"),
+    seed!("C799D57B41F28C5C446DFC58A5AC6499C4BCF3C162AFD2B09A16549826EC2A6F689E44BAFC4ACC82F5D6AEC23F4A3993"),
+    seed!("F7AE036A0176A9DE9A036A542DD2840033277C44AE936D10B768566216DE9D4395CD42B116873B69D9804BA6CCBC05D5"),
+    seed!("D995D38F934B6E1A7CA77C9522E3D037676CC939B0C8BD4B84394B3DC91A791F09D2D97199258C9943DA955E7F7B26FC"),
+    seed!("5929F02A271725CB40200DE32D9D03D8BEA53B53AC83186C42C7F565CCB1CA508305D470850CF86E9B2C61A5B8CA1C93"),
+    seed!("905074033D7B75DEB2D06A2F29144EB377B452534C5710632989F02D45312D156557E96D4486020826DB200153BC4A8B"),
+    seed!("A3E2E511AFA7BB560446BDADF67D2EE2E16FFC7BAEAE7EFB8C5455068BBD4E91BF9BE9D98B280072FABA7712C75B26D4"),
+    seed!("074AB1A37BA5A0403D8F68D26FB787BC2C90F5EF88F2A6D286C3E6B168ABD85D393D8225618608B8EEB301D26AF53BC0"),
+    seed!("CC0C86CC0ABF86FA21899BE1953913C00E7C46E6B5F730C4E88B3C034012763981D7F14459D3081638080378348856EA"),
+    seed!("6D5A7CC326ECF3983C4E7683F45263A37F692F3BCD2D920E1FD9584350119E74F9A3F905F70D3E20318C1413DE2A0DEA"),
+    seed!("F68FC0314DEA88F66AFAA76E6C9B6804B13D4876924410D1F526FAC59A62E26C560B125B1D0F8B461F1FC2E351EFFB4F"),
+    seed!("A229218B0D51F58D915DF549901548FB0722F352C7470900E7E4D8399205764A319BBDDBD06C00E8C5932722EE5A404D"),
+    seed!("6960F21C7350DCF41B4770C551DC8692D8BA2C0B6E162C589166FF22E7A1AC0F94C2F48504A5F7EB0DA094DF427BC98A"),
+    seed!("53DF46012CAD4A745B7A3C06E18CA95E0B839FD8161E3025749A0887549EB0ED6A44EEEA08BD6060D6509DBF7E9DC864"),
+    seed!("DEB963F8B1D8FBDF499D564BA8D2D47915BB402DA02F17031B37B4039A842AFB9B7E48F37200605992BD2429427A7A4E"),
+    seed!("8E2995F1B3E43853B18916BB1212ACEB05898E2B177A87ABEB928AD7184E59695C56B2CCCF5DB80853C28A525E327D13"),
+    seed!("9218943C51FD2DE47E509AAC67EFF176795102F37D7A2017E3AFD768FCDA7877AF38739B00FCDF227C2FD62EB635942C"),
+    seed!("542E20078ADD5296050AF150360F057F6B9AB3BA835589DD56987DE805F900B906505B5390A0D86CBA28038992DFC59A"),
+    seed!("6A85A61DD08C0733FCBC158ABB49FE0B0D96A50DCCA140A2E9F5A254F1901985844613B1C656C0CB0112620591B88AD0"),
+    seed!("7F4A56EDA151E7B097CFB8EF980440FFF707AFFBA91867C89522CED6C5FF3BD7F5F00BB49DDD615D9361A7E4EFA42851"),
+    seed!("09FC004519BCF85B20D25D314A0DFC79E00CB6262A7DDDF9C52473641AFB8CFA0F5DD5F53558184CAAE9EC34B459E98E"),
+    seed!("E3C41CCA6F04CFE7732FD54DE30CC5CAAC93E2F80E76AED7D24A962A3969C1B6A311459A3EC3E510E3E9B1E4291D4D7D"),
+    seed!("373FDDE922CFC416ED96B444E445BDD0962E8989F6C50ADF9912A89937C57217D3600B06C95440448E3F601AE69CA5BE"),
+    seed!("16BEF67F7AC3A755C59C816478B75FCC16CE5844DB537791ACCD1EBD49D2824B105FD2E970F728C8F0CF16E439A9AE2F"),
+    seed!("D0611F9AE5BE4DA5D7EADC9109944348E716CB3DAEE545721EEA8C892E7831CF2E54603146454CBFD92387739E9A78D8"),
+    seed!("FBC38D7614D7718E931EDB850D2C6F0C5EEA9EE889B3E25BD69AC255D5B91E885D93E808E66BF9C88C655DC594DA5792"),
+    seed!("1722219CB5DB47374EB0AF0232C856A57F026F1CB09E5A5799F4C333DD422FF6A0A67C4DA502FAAE727FB2D45DAFCF35"),
+    seed!("AC139B78FD16CA0F26D6D7F9E15345C888D857B1910CF38D883339B37EAD2DCAC30F7CF10176F23FF34B4488EB79437C"),
+    seed!("CC7152849C98D5FED2813275D32069E44824ECB14EAEF425CE017448CD9A401C91C06D0F7EED6D22B7BBE8BA6C429EC3"),
+    seed!("96D9A06F88FF2C2036FA8E914B89C765E4A510B468DEE40F914F78858C811857EFE9FD0E17C0048E7389E8D996B7E2B0"),
+    seed!("D26CE360D399BF7B89DC364AA7AC06BB513EAB8F527383E93E30727EDC3F22C262AA0EC70257B39EDFF0630DCDC1B79A"),
+    seed!("C5856298C3CB6AC9787A0F30938537AB2635B96F6D19CC9522063360E7A5C88E644929D2879180E3E5BCAD2422B7CFC3"),
+    seed!("A28EAD0A08E7228AEFF602B16A1E752278B8ED1E91DAC67994F5ADC372E1D82F95CC390CD97AB9212275E0566C833FD8"),
+    seed!("92877D706DAF88EF3412EB143DB8CD91BC047A9A43B7ACDAA42523560DEE4C172697BE4332042FCAB91135839BF74AB2"),
+    seed!("BB4C0082CA4044B1FF60B036C9B0E0495D58667156786C530BC69D949A13BFAFF53798E456423D7A0E162A60039367D7"),
+    seed!("121D90E70AF6204445D0DEB28AC0C108262719E9FD3476ACA74BBFDE89FAF04D8D5F89A624E8A75DB80431F0D10AD28F"),
+    seed!("B3AC6503206ACCC2A92CBC210D020A2654726911D11CE676AA04FEAA08AF1D20C654E4105883AE470EC3AB299075D420"),
+    seed!("59EFF60B1EF6185DB34EE1E3B1DD2F159106CECEAA79BEB74923B4F5623D5BC52DBF5D2594A1F7C6C64D12CF144E9ED4"),
+    seed!("DDDCA9DC31BEA737D3F474E7560B37FACB2F53C803E768FFAADE7669FF94B1D4FBD17068CFFD5DFDD24AADADA4EF6B12"),
+    seed!("2A6F7386B815366F572AEB6C79E272CC21B7095FE09575F18072C9D677DA23BC9C8A4BC393B7524604D299BEDD260C8B"),
+];
+
+#[cfg(test)]
+mod test {
+    use rand_core::RngCore;
+
+    use super::*;
+    use crate::SubSeeds;
+
+    #[test]
+    fn standard_kat_rngs() {
+        let mut rngs = super::standard_kat_rngs();
+        assert_eq!(rngs.len(), COUNTS);
+
+        let mut master = NistPqcAes256CtrRng::from(ENTROPY_INPUT);
+        for ((count, mut rng), seed) in rngs.by_ref().zip(SubSeeds::new(&mut master)) {
+            let mut expected = NistPqcAes256CtrRng::from_seed(seed);
+            assert_eq!(rng.next_u64(), expected.next_u64(), "count {}", count);
+        }
+        assert_eq!(rngs.len(), 0);
+        assert!(rngs.next().is_none());
+    }
+
+    #[test]
+    fn first_count() {
+        let (count, mut rng) = super::standard_kat_rngs().next().unwrap();
+        assert_eq!(count, 0);
+        let mut expected = NistPqcAes256CtrRng::from_seed(crate::seed!(
+            "061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7056A8C266F9EF97ED08541DBD2E1FFA1"
+        ));
+        assert_eq!(rng.next_u64(), expected.next_u64());
+    }
+
+    #[test]
+    fn kat_seed() {
+        let mut master = NistPqcAes256CtrRng::from(ENTROPY_INPUT);
+        for (count, seed) in SubSeeds::new(&mut master).take(10).enumerate() {
+            assert_eq!(super::kat_seed(count).into_inner(), seed.into_inner());
+        }
+        assert_eq!(
+            super::kat_seed(0).into_inner(),
+            crate::seed!(
+                "061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7056A8C266F9EF97ED08541DBD2E1FFA1"
+            )
+            .into_inner()
+        );
+    }
+
+    #[cfg(feature = "kat-seeds")]
+    #[test]
+    fn kat_seeds() {
+        let mut master = NistPqcAes256CtrRng::from(ENTROPY_INPUT);
+        for (seed, expected) in KAT_SEEDS.iter().zip(SubSeeds::new(&mut master)) {
+            assert_eq!(seed.clone().into_inner(), expected.into_inner());
+        }
+        assert_eq!(
+            super::kat_seed(COUNTS).into_inner(),
+            SubSeeds::new(&mut master).next().unwrap().into_inner()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn kat_writer() {
+        use std::vec::Vec;
+
+        let seed = super::kat_seed(0);
+        let mut writer = KatWriter::new(Vec::new());
+        writer.write_header("Test").unwrap();
+        writer
+            .write_response(&KemRecord {
+                count: 0,
+                seed: &seed,
+                pk: &[0x01, 0xab],
+                sk: &[0xff],
+                ct: &[],
+                ss: &[0x00, 0x10],
+            })
+            .unwrap();
+        assert_eq!(
+            writer.into_inner(),
+            b"# Test\n\n\
+              count = 0\n\
+              seed = 061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7056A8C266F9EF97ED08541DBD2E1FFA1\n\
+              pk = 01AB\n\
+              sk = FF\n\
+              ct = 00\n\
+              ss = 0010\n\
+              \n"
+        );
+
+        let mut writer = KatWriter::new(Vec::new());
+        writer.write_request(1, &super::kat_seed(1)).unwrap();
+        assert_eq!(
+            writer.into_inner(),
+            b"count = 1\n\
+              seed = D81C4D8D734FCBFBEADE3D3F8A039FAA2A2C9957E835AD55B22E75BF57BB556AC81ADDE6AEEB4A5A875C3BFCADFA958F\n\
+              pk =\n\
+              sk =\n\
+              ct =\n\
+              ss =\n\
+              \n"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sign_kat_writer() {
+        use std::vec::Vec;
+
+        let seed = super::kat_seed(0);
+        let mut writer = SignKatWriter::new(Vec::new());
+        writer.write_header("Test").unwrap();
+        writer
+            .write_response(&SignRecord {
+                count: 0,
+                seed: &seed,
+                msg: &[0xd8, 0x1c],
+                pk: &[0x01, 0xab],
+                sk: &[],
+                sm: &[0xff, 0xd8, 0x1c],
+            })
+            .unwrap();
+        assert_eq!(
+            writer.into_inner(),
+            b"# Test\n\n\
+              count = 0\n\
+              seed = 061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7056A8C266F9EF97ED08541DBD2E1FFA1\n\
+              mlen = 2\n\
+              msg = D81C\n\
+              pk = 01AB\n\
+              sk = 00\n\
+              smlen = 3\n\
+              sm = FFD81C\n\
+              \n"
+        );
+
+        let mut writer = SignKatWriter::new(Vec::new());
+        writer.write_request(1, &seed, &[0x0a]).unwrap();
+        assert_eq!(
+            writer.into_inner(),
+            b"count = 1\n\
+              seed = 061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7056A8C266F9EF97ED08541DBD2E1FFA1\n\
+              mlen = 1\n\
+              msg = 0A\n\
+              pk =\n\
+              sk =\n\
+              smlen =\n\
+              sm =\n\
+              \n"
+        );
+    }
+
+    #[test]
+    fn counts() {
+        assert!(super::standard_kat_rngs()
+            .map(|(count, _)| count)
+            .eq(0..COUNTS));
+    }
+}
@@ -35,42 +35,390 @@
 //! let seed = b"012345678901234567890123456789012345678901234567".as_slice();
 //! let rng = NistPqcAes256CtrRng::try_from(seed).expect("seed of invalid length");
 //! ```
+//!
+//! ## Seeding from the operating system
+//!
+//! With the `getrandom` feature, all seeded RNGs can be instantiated from the
+//! operating system's RNG via `SeedableRng::from_entropy` (and
+//! `SeedableRng::from_os_rng` of `rand_core` 0.9). The exception is
+//! `PredictionResistantCtrDrbg`, which draws its entropy from an
+//! `EntropySource` instead:
+//! ```
+//! # #[cfg(feature = "getrandom")]
+//! # {
+//! use nist_pqc_seeded_rng::{NistPqcAes256CtrRng, SeedableRng};
+//!
+//! let rng = NistPqcAes256CtrRng::from_entropy();
+//! # }
+//! ```
+//!
+//! On targets without a default backend of `getrandom`, e.g.,
+//! `wasm32-unknown-unknown` or embedded targets, the backend has to be
+//! selected via the features of `getrandom` (`js` or `custom`).
+//!
+//! ## Versions of `rand_core`
+//!
+//! The RNGs implement the traits of `rand_core` 0.6, which are re-exported by
+//! this crate. With the `rand_core-0_9` feature, the RNGs additionally
+//! implement [RngCore](rand_core_0_9::RngCore),
+//! [CryptoRng](rand_core_0_9::CryptoRng) and
+//! [SeedableRng](rand_core_0_9::SeedableRng) of `rand_core` 0.9. Both
+//! generations produce the same output, so that a single RNG can be passed to
+//! consumers of either generation. The dependency on `rand_core` 0.6 is not
+//! optional since the API of this crate is built on its traits and error type:
+//! ```
+//! # #[cfg(feature = "rand_core-0_9")]
+//! # {
+//! use nist_pqc_seeded_rng::{NistPqcAes256CtrRng, Seed, SeedableRng};
+//!
+//! fn consumer_0_6(rng: &mut impl nist_pqc_seeded_rng::CryptoRng) {}
+//! fn consumer_0_9(rng: &mut impl rand_core_0_9::CryptoRng) {}
+//!
+//! let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+//! consumer_0_6(&mut rng);
+//! consumer_0_9(&mut rng);
+//! # }
+//! ```
+//!
+//! ## AES backend
+//!
+//! The AES implementation is provided by the [aes] crate, which selects the
+//! backend at compile time and, on `x86` and `x86_64`, via runtime detection
+//! of AES-NI. The selection is controlled by configuration flags of [aes]
+//! instead of cargo features, hence it can not be forwarded by a feature of
+//! this crate. The following flags are available:
+//!
+//! - `aes_armv8`: use the ARMv8 cryptography extensions on `aarch64`, which
+//!   are detected at runtime on Linux and macOS. Without this flag, the
+//!   software implementation is used on `aarch64`.
+//! - `aes_force_soft`: always use the portable software implementation.
+//!
+//! The output of the RNGs is independent of the backend. To guarantee that the
+//! bitsliced constant-time software implementation is used regardless of the
+//! target features, build with
+//! ```text
+//! RUSTFLAGS="--cfg aes_force_soft" cargo build
+//! ```
+//! or set the flag for the target in `.cargo/config.toml`:
+//! ```toml
+//! [target.thumbv7em-none-eabihf]
+//! rustflags = ["--cfg", "aes_force_soft"]
+//! ```
+//!
+//! Similarly, the hardware backend for `aarch64` is enabled with
+//! `RUSTFLAGS="--cfg aes_armv8"`.
 
-use core::{ops::Index, slice::SliceIndex};
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-use aes::cipher::{generic_array::GenericArray, KeyIvInit, StreamCipher, StreamCipherSeek};
+use core::{
+    fmt,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ops::{Index, IndexMut},
+    slice::SliceIndex,
+};
+
+use aes::{
+    cipher::{consts::U16, BlockCipher, BlockEncrypt, KeyInit, StreamCipher},
+    Aes128, Aes192, Aes256,
+};
+use guard::ZeroizeGuard;
 pub use rand_core::{CryptoRng, RngCore, SeedableRng};
 
-type Aes256Ctr = ctr::Ctr128BE<aes::Aes256>;
+pub mod budget;
+pub mod buffered;
+pub mod call_pattern;
+#[cfg(feature = "chacha")]
+pub mod chacha;
+mod checkpoint;
+pub mod continuous;
+#[cfg(feature = "alloc")]
+pub mod counting;
+pub mod ctr_drbg;
+#[cfg(feature = "alloc")]
+pub mod diff;
+mod drbg;
+pub mod dyn_drbg;
+#[cfg(feature = "critical-section")]
+pub mod embedded;
+pub mod entropy;
+mod error;
+pub mod expect;
+#[cfg(feature = "digest")]
+mod fingerprint;
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+#[cfg(feature = "std")]
+pub mod global;
+mod guard;
+#[cfg(feature = "hash-drbg")]
+pub mod hash_drbg;
+mod hex;
+#[cfg(feature = "hmac-drbg")]
+pub mod hmac_drbg;
+#[cfg(any(feature = "generic-array", feature = "hybrid-array"))]
+mod interop;
+pub mod kat;
+mod nist_drbg;
+#[cfg(feature = "rand_core-0_9")]
+mod rand09;
+#[cfg(feature = "alloc")]
+pub mod recording;
+pub mod replay;
+pub mod seed_expander;
+#[cfg(feature = "serde")]
+mod serde_array;
+#[cfg(feature = "serde_bytes")]
+pub mod serde_compact;
+#[cfg(feature = "serde")]
+mod serde_seed;
+#[cfg(feature = "shake")]
+pub mod shake;
+pub mod shared;
+
+pub use buffered::{Chunks, IterBytes, WordBufferedRng};
+#[cfg(feature = "chacha")]
+pub use chacha::ChaCha20SeededRng;
+pub use checkpoint::Checkpoint;
+pub use continuous::ContinuousMode;
+pub use ctr_drbg::{CtrDrbg, CtrDrbgBuilder, PredictionResistantCtrDrbg, SecurityStrength};
+#[cfg(feature = "alloc")]
+pub use dyn_drbg::DrbgKind;
+pub use dyn_drbg::DynDrbg;
+pub use entropy::EntropySource;
+#[cfg(feature = "getrandom")]
+pub use entropy::OsEntropy;
+pub use error::{Error, InvalidSeedLength, ParseSeedError};
+#[cfg(feature = "digest")]
+pub use fingerprint::RngXofReader;
+#[cfg(feature = "hash-drbg")]
+pub use hash_drbg::HashDrbgSha256;
+#[cfg(feature = "hmac-drbg")]
+pub use hmac_drbg::{HmacDrbg, HmacDrbgSha256, HmacDrbgSha512};
+pub use nist_drbg::{DrbgParameters, NistDrbg};
+#[cfg(feature = "rand_core-0_9")]
+pub use rand09::{CompatRng, FallibleRng};
+pub use seed_expander::NistSeedExpander;
+#[cfg(feature = "shake")]
+pub use shake::{Shake128Rng, Shake256Rng};
 
 const KEY_LENGTH: usize = 32;
 const V_LENGTH: usize = 16;
 const SEED_LENGTH: usize = KEY_LENGTH + V_LENGTH;
 
-/// Represents a seed which consists of 48 bytes.
-#[derive(Debug)]
-#[cfg_attr(feature = "zeroize", derive(zeroize::ZeroizeOnDrop))]
-pub struct Seed([u8; SEED_LENGTH]);
+const AES128_KEY_LENGTH: usize = 16;
+const AES128_SEED_LENGTH: usize = AES128_KEY_LENGTH + V_LENGTH;
+const AES192_KEY_LENGTH: usize = 24;
+const AES192_SEED_LENGTH: usize = AES192_KEY_LENGTH + V_LENGTH;
+
+/// Seed consisting of `N` bytes
+///
+/// The seeds of all RNGs share this type. [Seed], [Aes128Seed] and
+/// [Aes192Seed] are the seeds of the CTR_DRBG variants.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop))]
+#[repr(transparent)]
+pub struct SeedN<const N: usize>([u8; N]);
+
+// SAFETY: the all-zero byte array is a valid seed
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Zeroable for SeedN<N> {}
+
+// SAFETY: the seed is a `repr(transparent)` wrapper of the byte array
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::TransparentWrapper<[u8; N]> for SeedN<N> {}
+
+impl<const N: usize> SeedN<N> {
+    /// Create a seed from its bytes.
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+
+    /// Return the bytes of the seed.
+    ///
+    /// With the `zeroize` feature, the seed itself is zeroized on drop.
+    pub fn into_inner(self) -> [u8; N] {
+        self.0
+    }
+
+    /// XOR `other` into the seed.
+    ///
+    /// This mirrors how `randombytes_init` of `rng.c` folds the
+    /// personalization string into the entropy input, e.g., to derive
+    /// related but distinct seeds.
+    pub fn xor_with(&mut self, other: &[u8; N]) {
+        self.0.iter_mut().zip(other).for_each(|(x, y)| *x ^= y);
+    }
+
+    /// Return the XOR of the seed and `other`.
+    pub fn xored(&self, other: &[u8; N]) -> Self {
+        let mut seed = Self(self.0);
+        seed.xor_with(other);
+        seed
+    }
+
+    /// Draw a seed from `rng`.
+    ///
+    /// The seed consists of the output of a single request to
+    /// [fill_bytes](RngCore::fill_bytes).
+    pub fn generate<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        let mut seed = Self::default();
+        rng.fill_bytes(&mut seed.0);
+        seed
+    }
+
+    /// Derive a seed from a passphrase.
+    ///
+    /// The seed consists of the first bytes of the output of SHAKE256
+    /// on the UTF-8 encoding of the passphrase. Hence, it is easily
+    /// reproduced in other languages.
+    #[cfg(feature = "shake")]
+    pub fn from_passphrase(passphrase: &str) -> Self {
+        use sha3::digest::ExtendableOutput;
+
+        let mut seed = Self::default();
+        sha3::Shake256::digest_xof(passphrase.as_bytes(), &mut seed.0);
+        seed
+    }
+
+    /// Generate a random seed using the operating system's RNG.
+    ///
+    /// Record the seed, e.g., via its [Display](fmt::Display)
+    /// implementation, to reproduce the outputs later on.
+    #[cfg(feature = "getrandom")]
+    pub fn random() -> Result<Self, Error> {
+        let mut seed = Self::default();
+        OsEntropy.fill_entropy(&mut seed.0)?;
+        Ok(seed)
+    }
+
+    /// Parse a seed from a hex string as found in the KAT files.
+    ///
+    /// Lowercase and uppercase digits are accepted. The string has to
+    /// consist of exactly two digits per byte of the seed.
+    pub fn from_hex(s: &str) -> Result<Self, ParseSeedError> {
+        let mut seed = Self::default();
+        hex::decode(s, &mut seed.0)?;
+        Ok(seed)
+    }
+
+    /// Parse a seed from a base64 string with padding.
+    #[cfg(feature = "base64")]
+    pub fn from_base64(s: &str) -> Result<Self, ParseSeedError> {
+        use base64::Engine;
+
+        let expected = base64::encoded_len(N, true).expect("seed length is small");
+        if s.len() != expected {
+            return Err(ParseSeedError::InvalidLength {
+                expected,
+                actual: s.len(),
+            });
+        }
+        let mut seed = Self::default();
+        match base64::engine::general_purpose::STANDARD.decode_slice(s, &mut seed.0) {
+            Ok(length) if length == N => Ok(seed),
+            _ => Err(ParseSeedError::InvalidBase64),
+        }
+    }
+
+    /// Encode the seed as base64 string with padding.
+    #[cfg(feature = "base64")]
+    pub fn to_base64(&self) -> alloc::string::String {
+        use base64::Engine;
+
+        base64::engine::general_purpose::STANDARD.encode(self.0)
+    }
+}
+
+/// With the `zeroize` feature, the contents of the vector are zeroized
+/// before it is freed.
+#[cfg(feature = "alloc")]
+impl<const N: usize> TryFrom<alloc::vec::Vec<u8>> for SeedN<N> {
+    type Error = InvalidSeedLength;
+
+    #[allow(unused_mut)]
+    fn try_from(mut value: alloc::vec::Vec<u8>) -> Result<Self, Self::Error> {
+        let seed = Self::try_from(value.as_slice());
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(value.as_mut_slice());
+        seed
+    }
+}
+
+/// With the `zeroize` feature, the box is zeroized before it is freed.
+#[cfg(feature = "alloc")]
+impl<const N: usize> From<alloc::boxed::Box<[u8; N]>> for SeedN<N> {
+    #[allow(unused_mut)]
+    fn from(mut value: alloc::boxed::Box<[u8; N]>) -> Self {
+        let seed = Self(*value);
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(value.as_mut());
+        seed
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<const N: usize> subtle::ConstantTimeEq for SeedN<N> {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
 
-impl Default for Seed {
+impl<const N: usize> fmt::LowerHex for SeedN<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        hex::encode(f, &self.0, false)
+    }
+}
+
+impl<const N: usize> fmt::UpperHex for SeedN<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        hex::encode(f, &self.0, true)
+    }
+}
+
+/// Formats the seed as uppercase hex string as in the KAT files.
+impl<const N: usize> fmt::Display for SeedN<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(self, f)
+    }
+}
+
+impl<const N: usize> core::str::FromStr for SeedN<N> {
+    type Err = ParseSeedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+impl<const N: usize> Default for SeedN<N> {
     fn default() -> Self {
-        Self([0u8; SEED_LENGTH])
+        Self([0u8; N])
     }
 }
 
-impl AsRef<[u8]> for Seed {
+impl<const N: usize> AsRef<[u8]> for SeedN<N> {
     fn as_ref(&self) -> &[u8] {
         &self.0
     }
 }
 
-impl AsMut<[u8]> for Seed {
+impl<const N: usize> AsRef<[u8; N]> for SeedN<N> {
+    fn as_ref(&self) -> &[u8; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsMut<[u8]> for SeedN<N> {
     fn as_mut(&mut self) -> &mut [u8] {
         &mut self.0
     }
 }
 
-impl<Idx> Index<Idx> for Seed
+impl<Idx, const N: usize> Index<Idx> for SeedN<N>
 where
     Idx: SliceIndex<[u8]>,
 {
@@ -81,76 +429,825 @@ where
     }
 }
 
-impl From<[u8; SEED_LENGTH]> for Seed {
-    fn from(value: [u8; SEED_LENGTH]) -> Self {
+impl<Idx, const N: usize> IndexMut<Idx> for SeedN<N>
+where
+    Idx: SliceIndex<[u8]>,
+{
+    fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for SeedN<N> {
+    fn from(value: [u8; N]) -> Self {
         Self(value)
     }
 }
 
-impl TryFrom<&[u8]> for Seed {
-    type Error = ();
+impl<const N: usize> From<SeedN<N>> for [u8; N] {
+    fn from(value: SeedN<N>) -> Self {
+        value.into_inner()
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for SeedN<N> {
+    type Error = InvalidSeedLength;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        if value.len() == SEED_LENGTH {
-            let mut buf = [0; SEED_LENGTH];
+        if value.len() == N {
+            let mut buf = [0; N];
             buf.copy_from_slice(value);
             Ok(Self(buf))
         } else {
-            Err(())
+            Err(InvalidSeedLength {
+                expected: N,
+                actual: value.len(),
+            })
         }
     }
 }
 
-/// RNG used to generate known answer test values for NIST PQC competition
+macro_rules! impl_split_join {
+    ($length:expr, $key_length:expr) => {
+        impl SeedN<$length> {
+            /// Split the seed into the key and V parts of the seed material.
+            pub fn split(&self) -> (&[u8; $key_length], &[u8; V_LENGTH]) {
+                let (key, v) = self.0.split_at($key_length);
+                (
+                    key.try_into().expect("key part has the key length"),
+                    v.try_into().expect("V part has the block length"),
+                )
+            }
+
+            /// Join the key and V parts of the seed material into a seed.
+            pub fn join(key: &[u8; $key_length], v: &[u8; V_LENGTH]) -> Self {
+                let mut seed = Self::default();
+                seed.0[..$key_length].copy_from_slice(key);
+                seed.0[$key_length..].copy_from_slice(v);
+                seed
+            }
+        }
+    };
+}
+
+impl_split_join!(SEED_LENGTH, KEY_LENGTH);
+impl_split_join!(AES128_SEED_LENGTH, AES128_KEY_LENGTH);
+impl_split_join!(AES192_SEED_LENGTH, AES192_KEY_LENGTH);
+
+/// Represents a seed which consists of 48 bytes.
+pub type Seed = SeedN<SEED_LENGTH>;
+
+/// Represents a seed for [NistAes128CtrRng] which consists of 32 bytes.
+pub type Aes128Seed = SeedN<AES128_SEED_LENGTH>;
+
+/// Represents a seed for [NistAes192CtrRng] which consists of 40 bytes.
+pub type Aes192Seed = SeedN<AES192_SEED_LENGTH>;
+
+/// Block ciphers that can be used with [SeededCtrDrbg]
 ///
-/// Warning: Do not use this RNG anywhere else. Its only use is to generate the
-/// responses for the known answer tests for schemes submitted to the NIST PQC
-/// competition.
+/// The block cipher needs to operate on 128 bit blocks and use keys of at most
+/// 256 bits.
+pub trait CtrDrbgCipher: BlockCipher<BlockSize = U16> + BlockEncrypt + KeyInit {
+    /// Seed of the DRBG consisting of key length plus block length bytes.
+    type Seed: AsRef<[u8]> + AsMut<[u8]> + Default;
+}
+
+impl CtrDrbgCipher for Aes128 {
+    type Seed = Aes128Seed;
+}
+
+impl CtrDrbgCipher for Aes192 {
+    type Seed = Aes192Seed;
+}
+
+impl CtrDrbgCipher for Aes256 {
+    type Seed = Seed;
+}
+
+/// Variants of the output generation of [SeededCtrDrbg]
+///
+/// Besides the reference `rng.c`, some submission packages and ports derived
+/// from it differ in how many blocks of the keystream are consumed per
+/// request. The modes describe the behavior independent of specific packages.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompatMode {
+    /// Behavior of the reference `rng.c`
+    ///
+    /// The request is rounded up to a multiple of the block length, i.e., the
+    /// remainder of the last block is discarded.
+    #[default]
+    Reference,
+    /// Number of blocks computed as `length / 16 + 1`
+    ///
+    /// Requests whose length is a multiple of the block length, including
+    /// empty requests, consume one additional block of the keystream. Other
+    /// requests agree with [CompatMode::Reference].
+    AlignedExtraBlock,
+}
+
+/// Seeded CTR_DRBG as used in `rng.c` generic over the block cipher
+///
+/// The DRBG corresponds to CTR_DRBG from NIST SP 800-90A without derivation
+/// function. With AES, it produces the same output as the corresponding
+/// CTR_DRBG from `rng.c`. Reseeding is supported via [reseed](Self::reseed).
+///
+/// Like `rng.c`, the RNG counts the requests since the last reseed but
+/// ignores the reseed interval and the maximal request size, i.e., it never
+/// requires a reseed. RNGs instantiated with
+/// [from_seed_strict](Self::from_seed_strict) instead reject requests
+/// violating these limits.
+///
+/// With the `zeroize` feature, the state is zeroized on drop and can be wiped
+/// on demand via `Zeroize`. Afterwards, the RNG behaves like an RNG
+/// instantiated with all-zero key and V, i.e., the reseed counter is 1 and the
+/// position is 0. The strict mode and the [CompatMode] are kept.
+///
+/// Warning: Do not use this RNG for anything else than testing.
 #[derive(Debug)]
-#[cfg_attr(feature = "zeroize", derive(zeroize::ZeroizeOnDrop))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct NistPqcAes256CtrRng {
+pub struct SeededCtrDrbg<C: CtrDrbgCipher> {
+    // only the first `C::key_size()` bytes are used
+    #[cfg_attr(feature = "serde", serde(with = "serde_array"))]
     key: [u8; KEY_LENGTH],
+    #[cfg_attr(feature = "serde", serde(with = "serde_array"))]
     v: [u8; V_LENGTH],
+    // the defaults match the state after instantiation to accept states
+    // serialized with only the key and V
+    #[cfg_attr(feature = "serde", serde(default = "initial_reseed_counter"))]
+    reseed_counter: u64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    position: u64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    last_request_length: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    strict: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    compat_mode: CompatMode,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cipher: PhantomData<C>,
 }
 
-impl SeedableRng for NistPqcAes256CtrRng {
-    type Seed = Seed;
+#[cfg(feature = "serde")]
+const fn initial_reseed_counter() -> u64 {
+    1
+}
+
+/// Create a [Seed] from a hex string literal at compile time
+///
+/// Lowercase and uppercase digits are accepted. Strings of invalid length or
+/// with invalid characters are rejected at compile time.
+/// ```
+/// use nist_pqc_seeded_rng::{seed, NistPqcAes256CtrRng, SeedableRng};
+///
+/// let seed = seed!("061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7056A8C266F9EF97ED08541DBD2E1FFA1");
+/// let rng = NistPqcAes256CtrRng::from_seed(seed);
+/// ```
+///
+/// ```compile_fail
+/// let seed = nist_pqc_seeded_rng::seed!("0615");
+/// ```
+#[macro_export]
+macro_rules! seed {
+    ($hex:literal) => {{
+        const SEED: [u8; 48] = $crate::__decode_hex($hex);
+        $crate::Seed::new(SEED)
+    }};
+}
+
+#[doc(hidden)]
+pub use hex::decode_array as __decode_hex;
 
-    fn from_seed(mut seed: Self::Seed) -> Self {
-        let mut cipher = Aes256Ctr::new(&GenericArray::default(), &GenericArray::default());
-        cipher.seek(16);
-        cipher.apply_keystream(seed.as_mut());
+/// RNG used to generate known answer test values for NIST PQC competition
+///
+/// Warning: Do not use this RNG anywhere else. Its only use is to generate the
+/// responses for the known answer tests for schemes submitted to the NIST PQC
+/// competition.
+pub type NistPqcAes256CtrRng = SeededCtrDrbg<Aes256>;
+
+/// Variant of [NistPqcAes256CtrRng] using AES-128 and a 32 byte seed
+///
+/// This RNG corresponds to CTR_DRBG without derivation function at a
+/// security strength of 128 bits, e.g., to validate the DRBG logic against
+/// CAVP test vectors.
+///
+/// Warning: Do not use this RNG for anything else than testing.
+pub type NistAes128CtrRng = SeededCtrDrbg<Aes128>;
+
+/// Variant of [NistPqcAes256CtrRng] using AES-192 and a 40 byte seed
+///
+/// This RNG corresponds to CTR_DRBG without derivation function at a
+/// security strength of 192 bits, e.g., to validate the DRBG logic against
+/// CAVP test vectors.
+///
+/// Warning: Do not use this RNG for anything else than testing.
+pub type NistAes192CtrRng = SeededCtrDrbg<Aes192>;
+
+// derived implementation would require `C: Clone`
+impl<C: CtrDrbgCipher> Clone for SeededCtrDrbg<C> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key,
+            v: self.v,
+            reseed_counter: self.reseed_counter,
+            position: self.position,
+            last_request_length: self.last_request_length,
+            strict: self.strict,
+            compat_mode: self.compat_mode,
+            cipher: PhantomData,
+        }
+    }
+}
+
+// derived implementation would reset the reseed counter to 0 and clear the
+// configuration
+#[cfg(feature = "zeroize")]
+impl<C: CtrDrbgCipher> zeroize::Zeroize for SeededCtrDrbg<C> {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+        self.v.zeroize();
+        self.reseed_counter = 1;
+        self.position = 0;
+        self.last_request_length = 0;
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: CtrDrbgCipher> Drop for SeededCtrDrbg<C> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: CtrDrbgCipher> zeroize::ZeroizeOnDrop for SeededCtrDrbg<C> {}
+
+/// Compares the key, V and the reseed counter in constant time.
+#[cfg(feature = "subtle")]
+impl<C: CtrDrbgCipher> subtle::ConstantTimeEq for SeededCtrDrbg<C> {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.key.ct_eq(&other.key)
+            & self.v.ct_eq(&other.v)
+            & self.reseed_counter.ct_eq(&other.reseed_counter)
+    }
+}
+
+impl<C: CtrDrbgCipher> SeedableRng for SeededCtrDrbg<C> {
+    type Seed = C::Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        assert!(C::key_size() <= KEY_LENGTH);
+        debug_assert_eq!(seed.as_ref().len(), C::key_size() + V_LENGTH);
+
+        let mut rng = Self {
+            key: [0; KEY_LENGTH],
+            v: [0; V_LENGTH],
+            reseed_counter: 1,
+            position: 0,
+            last_request_length: 0,
+            strict: false,
+            compat_mode: CompatMode::Reference,
+            cipher: PhantomData,
+        };
+        drbg::update::<C>(&mut rng.key[..C::key_size()], &mut rng.v, seed.as_ref());
+        rng
+    }
+
+    /// Instantiate the RNG from a `u64`.
+    ///
+    /// The seed is derived from the little-endian encoding of `state` using
+    /// the derivation function `Block_Cipher_df` of NIST SP 800-90A with the
+    /// block cipher of the RNG. Hence, the RNG produces the same output as
+    /// [CtrDrbg] instantiated with the encoding as entropy input and an empty
+    /// personalization string.
+    fn seed_from_u64(state: u64) -> Self {
+        Self::from_seed(seed_from_u64::<C>(state))
+    }
+}
 
+impl SeededCtrDrbg<Aes256> {
+    /// Instantiate the RNG directly from the internal state of the DRBG.
+    ///
+    /// The key and V are used as is without applying `CTR_DRBG_Update`, e.g.,
+    /// to inject a state dumped via [key](Self::key) and [v](Self::v) from
+    /// another implementation. The reseed counter starts at 1.
+    pub const fn from_state(key: [u8; KEY_LENGTH], v: [u8; V_LENGTH]) -> Self {
+        Self {
+            key,
+            v,
+            reseed_counter: 1,
+            position: 0,
+            last_request_length: 0,
+            strict: false,
+            compat_mode: CompatMode::Reference,
+            cipher: PhantomData,
+        }
+    }
+
+    /// Encode the internal state of the DRBG as key || V.
+    ///
+    /// The reseed counter, the position and the configuration of the RNG are
+    /// not included.
+    pub fn to_state_bytes(&self) -> [u8; SEED_LENGTH] {
+        let mut bytes = [0; SEED_LENGTH];
+        bytes[..KEY_LENGTH].copy_from_slice(&self.key);
+        bytes[KEY_LENGTH..].copy_from_slice(&self.v);
+        bytes
+    }
+
+    /// Instantiate the RNG from a state encoded by
+    /// [to_state_bytes](Self::to_state_bytes).
+    ///
+    /// This is equivalent to [from_state](Self::from_state) with the key and V
+    /// taken from the encoding.
+    pub const fn from_state_bytes(bytes: [u8; SEED_LENGTH]) -> Self {
         let mut key = [0; KEY_LENGTH];
         let mut v = [0; V_LENGTH];
-        key.copy_from_slice(&seed[..KEY_LENGTH]);
-        v.copy_from_slice(&seed[KEY_LENGTH..]);
-        Self { key, v }
+        // `copy_from_slice` is not available in const contexts
+        let mut i = 0;
+        while i < SEED_LENGTH {
+            if i < KEY_LENGTH {
+                key[i] = bytes[i];
+            } else {
+                v[i - KEY_LENGTH] = bytes[i];
+            }
+            i += 1;
+        }
+        Self::from_state(key, v)
+    }
+}
+
+/// Derive a seed from a `u64` using `Block_Cipher_df`.
+pub(crate) fn seed_from_u64<C: CtrDrbgCipher>(state: u64) -> C::Seed {
+    let mut seed = C::Seed::default();
+    ctr_drbg::df::<C>(&[&state.to_le_bytes()], seed.as_mut());
+    seed
+}
+
+impl<C: CtrDrbgCipher> SeededCtrDrbg<C> {
+    /// Instantiate the RNG from a seed and enforce the limits of SP 800-90A.
+    ///
+    /// Once [RESEED_INTERVAL](ctr_drbg::RESEED_INTERVAL) requests have been
+    /// served since the last reseed, [try_fill_bytes](RngCore::try_fill_bytes)
+    /// fails with [Error::ReseedRequired]. Requests of more than
+    /// [MAX_BYTES_PER_REQUEST](ctr_drbg::MAX_BYTES_PER_REQUEST) bytes fail with
+    /// [Error::RequestTooLarge]. In both cases, the panicking functions panic.
+    pub fn from_seed_strict(seed: C::Seed) -> Self {
+        let mut rng = Self::from_seed(seed);
+        rng.strict = true;
+        rng
+    }
+
+    /// Instantiate the RNG from a seed reproducing the given variant of
+    /// `rng.c`.
+    pub fn from_seed_with_compat_mode(seed: C::Seed, compat_mode: CompatMode) -> Self {
+        let mut rng = Self::from_seed(seed);
+        rng.compat_mode = compat_mode;
+        rng
+    }
+
+    /// Instantiate the RNG from a seed drawn from the operating system's RNG
+    /// and return the seed.
+    ///
+    /// Record the seed to reproduce a failing run later on:
+    /// ```
+    /// use nist_pqc_seeded_rng::NistPqcAes256CtrRng;
+    ///
+    /// let (rng, seed) = NistPqcAes256CtrRng::from_os_entropy().expect("OS RNG failed");
+    /// println!("seed: {}", seed);
+    /// ```
+    #[cfg(feature = "getrandom")]
+    pub fn from_os_entropy() -> Result<(Self, C::Seed), Error>
+    where
+        C::Seed: Clone,
+    {
+        let mut seed = C::Seed::default();
+        OsEntropy.fill_entropy(seed.as_mut())?;
+        Ok((Self::from_seed(seed.clone()), seed))
+    }
+
+    /// Variant of `rng.c` reproduced by the RNG.
+    pub fn compat_mode(&self) -> CompatMode {
+        self.compat_mode
+    }
+
+    /// Current key of the DRBG.
+    ///
+    /// The key is `C::key_size()` bytes long.
+    pub fn key(&self) -> &[u8] {
+        &self.key[..C::key_size()]
+    }
+
+    /// Current value V of the DRBG.
+    pub fn v(&self) -> &[u8; V_LENGTH] {
+        &self.v
+    }
+
+    /// Number of requests since the last reseed plus one.
+    pub fn reseed_counter(&self) -> u64 {
+        self.reseed_counter
+    }
+
+    /// Total number of bytes generated since instantiation.
+    ///
+    /// Bytes discarded with [advance](Self::advance) are included. Reseeding
+    /// does not reset the position.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Number of bytes generated by the last request.
+    pub fn last_request_length(&self) -> usize {
+        self.last_request_length
+    }
+
+    /// Reseed the RNG with a fresh entropy input and optional additional input.
+    ///
+    /// As the DRBG operates without derivation function, the additional input
+    /// is padded with zeros to the length of the seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the additional input is longer than the seed.
+    pub fn reseed(&mut self, entropy_input: &C::Seed, additional_input: Option<&[u8]>) {
+        let seed_length = C::key_size() + V_LENGTH;
+        let mut seed_material = [0; SEED_LENGTH];
+        let mut seed_material = ZeroizeGuard::new(&mut seed_material[..seed_length]);
+        seed_material.copy_from_slice(entropy_input.as_ref());
+        if let Some(additional_input) = additional_input {
+            assert!(
+                additional_input.len() <= seed_length,
+                "additional input too long"
+            );
+            seed_material
+                .iter_mut()
+                .zip(additional_input)
+                .for_each(|(x, y)| *x ^= y);
+        }
+        drbg::update::<C>(&mut self.key[..C::key_size()], &mut self.v, &seed_material);
+        self.reseed_counter = 1;
+    }
+
+    /// Derive a child RNG for the given label.
+    ///
+    /// The child is instantiated from a seed drawn from this RNG XORed with
+    /// the output of the derivation function applied to the label. Hence,
+    /// children derived with different labels or at different positions of
+    /// the output of this RNG produce independent outputs. The child inherits
+    /// the strict mode and the [CompatMode].
+    pub fn fork(&mut self, label: &[u8]) -> Self {
+        let seed_length = C::key_size() + V_LENGTH;
+        let mut seed = C::Seed::default();
+        self.fill_bytes(seed.as_mut());
+        let mut label_block = [0; SEED_LENGTH];
+        let mut label_block = ZeroizeGuard::new(&mut label_block[..seed_length]);
+        ctr_drbg::df::<C>(&[label], &mut label_block);
+        seed.as_mut()
+            .iter_mut()
+            .zip(label_block.iter())
+            .for_each(|(x, y)| *x ^= y);
+
+        let mut child = Self::from_seed(seed);
+        child.strict = self.strict;
+        child.compat_mode = self.compat_mode;
+        child
+    }
+
+    /// Fill `dest` with random bytes taking the additional input into account.
+    ///
+    /// The additional input is padded with zeros to the length of the seed.
+    /// With an empty additional input, this is equivalent to
+    /// [fill_bytes](RngCore::fill_bytes).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the additional input is longer than the seed or if the RNG
+    /// is strict and rejects the request.
+    pub fn fill_bytes_with_additional_input(&mut self, dest: &mut [u8], additional_input: &[u8]) {
+        self.try_generate(dest, additional_input)
+            .expect("request rejected in strict mode")
+    }
+
+    /// Draw an array of `N` random bytes.
+    ///
+    /// This is equivalent to a single call to
+    /// [fill_bytes](RngCore::fill_bytes) with a buffer of `N` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the RNG is strict and rejects the request.
+    pub fn gen_array<const N: usize>(&mut self) -> [u8; N] {
+        let mut array = [0; N];
+        self.fill_bytes(&mut array);
+        array
+    }
+
+    /// Draw a vector of `len` random bytes.
+    ///
+    /// This is equivalent to `randombytes(buf, len)` of `rng.c`, i.e., a
+    /// single call to [fill_bytes](RngCore::fill_bytes).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the RNG is strict and rejects the request.
+    #[cfg(feature = "alloc")]
+    pub fn generate_vec(&mut self, len: usize) -> alloc::vec::Vec<u8> {
+        let mut vec = alloc::vec![0; len];
+        self.fill_bytes(&mut vec);
+        vec
+    }
+
+    /// Fill a buffer of uninitialized bytes and return the initialized buffer.
+    ///
+    /// This is equivalent to a single call to
+    /// [fill_bytes](RngCore::fill_bytes) with a buffer of `dest.len()` bytes,
+    /// but the caller does not need to initialize the buffer beforehand. The
+    /// keystream is generated in a small buffer on the stack and copied to
+    /// `dest`, so that every byte of `dest` is written exactly once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the RNG is strict and rejects the request.
+    pub fn fill_uninit<'a>(&mut self, dest: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+        const BUFFER_LENGTH: usize = 256;
+
+        self.try_request(dest.len(), &[], |key, v, additional_input, skip_blocks| {
+            drbg::generate_with::<C>(key, v, additional_input, skip_blocks, |cipher| {
+                let mut buffer = [0; BUFFER_LENGTH];
+                let mut buffer = ZeroizeGuard::new(&mut buffer);
+                for chunk in dest.chunks_mut(BUFFER_LENGTH) {
+                    let buffer = &mut buffer[..chunk.len()];
+                    buffer.fill(0);
+                    cipher.apply_keystream(buffer);
+                    for (byte, value) in chunk.iter_mut().zip(buffer.iter()) {
+                        byte.write(*value);
+                    }
+                }
+            })
+        })
+        .expect("request rejected in strict mode");
+        // SAFETY: all bytes of `dest` were initialized by the request
+        unsafe { &mut *(dest as *mut [MaybeUninit<u8>] as *mut [u8]) }
+    }
+
+    /// Draw a random 128 bit integer.
+    ///
+    /// The integer is decoded in little-endian byte order from the output of a
+    /// single request of 16 bytes, i.e., one block of the keystream.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the RNG is strict and rejects the request.
+    pub fn next_u128(&mut self) -> u128 {
+        u128::from_le_bytes(self.gen_array())
+    }
+
+    /// Fill `dest` with random 32 bit words.
+    ///
+    /// The words are decoded in little-endian byte order from the output of a
+    /// single request of `4 * dest.len()` bytes. Hence, the result is the same
+    /// on all platforms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the RNG is strict and rejects the request.
+    pub fn fill_u32_slice(&mut self, dest: &mut [u32]) {
+        self.fill_words(dest, u32::from_le_bytes)
+    }
+
+    /// Fill `dest` with random 64 bit words.
+    ///
+    /// The words are decoded in little-endian byte order from the output of a
+    /// single request of `8 * dest.len()` bytes. Hence, the result is the same
+    /// on all platforms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the RNG is strict and rejects the request.
+    pub fn fill_u64_slice(&mut self, dest: &mut [u64]) {
+        self.fill_words(dest, u64::from_le_bytes)
+    }
+
+    fn fill_words<T, const W: usize>(&mut self, dest: &mut [T], from_le_bytes: fn([u8; W]) -> T) {
+        const BUFFER_LENGTH: usize = 64;
+
+        self.try_request(
+            W * dest.len(),
+            &[],
+            |key, v, additional_input, skip_blocks| {
+                drbg::generate_with::<C>(key, v, additional_input, skip_blocks, |cipher| {
+                    let mut buffer = [0; BUFFER_LENGTH];
+                    let mut buffer = ZeroizeGuard::new(&mut buffer);
+                    for chunk in dest.chunks_mut(BUFFER_LENGTH / W) {
+                        let buffer = &mut buffer[..W * chunk.len()];
+                        buffer.fill(0);
+                        cipher.apply_keystream(buffer);
+                        for (word, bytes) in chunk.iter_mut().zip(buffer.chunks_exact(W)) {
+                            *word = from_le_bytes(bytes.try_into().expect("chunk of word size"));
+                        }
+                    }
+                })
+            },
+        )
+        .expect("request rejected in strict mode")
+    }
+
+    /// Write the next `n` bytes of output as uppercase hex string as used in
+    /// the KAT files.
+    ///
+    /// The output is consumed in a single request of `n` bytes and written
+    /// incrementally:
+    /// ```
+    /// use nist_pqc_seeded_rng::{NistPqcAes256CtrRng, Seed, SeedableRng};
+    ///
+    /// let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+    /// let mut hex = String::new();
+    /// rng.write_hex_to(&mut hex, 4).unwrap();
+    /// assert_eq!(hex, "91618FE9");
+    /// ```
+    ///
+    /// If writing fails, the output is still consumed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the RNG is strict and rejects the request.
+    pub fn write_hex_to(&mut self, w: &mut impl fmt::Write, n: usize) -> fmt::Result {
+        let mut result = Ok(());
+        self.stream_output(n, |bytes| {
+            if result.is_ok() {
+                result = hex::encode(w, bytes, true);
+            }
+        });
+        result
+    }
+
+    /// Consume `n` bytes of output in a single request and pass them to
+    /// `output` in chunks.
+    fn stream_output(&mut self, n: usize, mut output: impl FnMut(&[u8])) {
+        const BUFFER_LENGTH: usize = 64;
+
+        self.try_request(n, &[], |key, v, additional_input, skip_blocks| {
+            drbg::generate_with::<C>(key, v, additional_input, skip_blocks, |cipher| {
+                let mut buffer = [0; BUFFER_LENGTH];
+                let mut buffer = ZeroizeGuard::new(&mut buffer);
+                let mut remaining = n;
+                while remaining > 0 {
+                    let buffer = &mut buffer[..remaining.min(BUFFER_LENGTH)];
+                    buffer.fill(0);
+                    cipher.apply_keystream(buffer);
+                    output(buffer);
+                    remaining -= buffer.len();
+                }
+            })
+        })
+        .expect("request rejected in strict mode")
+    }
+
+    /// Consume and discard `n` bytes of output.
+    ///
+    /// The state is updated exactly as for a single request of `n` bytes, so
+    /// that `rng.advance(n)` followed by further requests produces the same
+    /// output as `rng.fill_bytes(&mut [0; n])` followed by the same requests.
+    /// The discarded output is never computed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the RNG is strict and rejects the request.
+    pub fn advance(&mut self, n: usize) {
+        self.try_request(n, &[], |key, v, additional_input, skip_blocks| {
+            drbg::discard_skipping::<C>(key, v, n, additional_input, skip_blocks)
+        })
+        .expect("request rejected in strict mode")
+    }
+
+    /// Compute the output of the next request of `dest.len()` bytes without
+    /// advancing the RNG.
+    ///
+    /// This is the output of the next request without additional input, e.g.,
+    /// ```
+    /// use nist_pqc_seeded_rng::{NistPqcAes256CtrRng, RngCore, Seed, SeedableRng};
+    ///
+    /// let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+    /// let mut peeked = [0; 32];
+    /// rng.peek_bytes(&mut peeked);
+    /// let mut buf = [0; 32];
+    /// rng.fill_bytes(&mut buf);
+    /// assert_eq!(peeked, buf);
+    /// ```
+    ///
+    /// Note that the output of consecutive requests differs from the output
+    /// of a single request of the total length, hence only the next request
+    /// can be peeked.
+    pub fn peek_bytes(&self, dest: &mut [u8]) {
+        drbg::peek::<C>(&self.key[..C::key_size()], &self.v, dest);
+    }
+
+    fn try_generate(&mut self, dest: &mut [u8], additional_input: &[u8]) -> Result<(), Error> {
+        self.try_request(
+            dest.len(),
+            additional_input,
+            |key, v, additional_input, skip_blocks| {
+                drbg::generate_skipping::<C>(key, v, dest, additional_input, skip_blocks)
+            },
+        )
+    }
+
+    /// Generate `dest.len()` bytes like [try_generate](Self::try_generate), but
+    /// write the following bytes of the keystream to `tail` instead of
+    /// discarding them.
+    ///
+    /// `tail` has to cover at most the remainder of the last block.
+    pub(crate) fn try_generate_with_tail(
+        &mut self,
+        dest: &mut [u8],
+        tail: &mut [u8],
+    ) -> Result<(), Error> {
+        debug_assert!(tail.len() < V_LENGTH && (dest.len() + tail.len()) % V_LENGTH == 0);
+        self.try_request(dest.len(), &[], |key, v, additional_input, skip_blocks| {
+            drbg::generate_with::<C>(key, v, additional_input, skip_blocks, |cipher| {
+                dest.fill(0);
+                cipher.apply_keystream(dest);
+                tail.fill(0);
+                cipher.apply_keystream(tail);
+            })
+        })
+    }
+
+    /// Process a request of `length` bytes, with `generate` producing the
+    /// output from the key, V, the padded additional input and the number of
+    /// blocks to skip.
+    fn try_request(
+        &mut self,
+        length: usize,
+        additional_input: &[u8],
+        generate: impl FnOnce(&mut [u8], &mut [u8; V_LENGTH], &[u8], usize),
+    ) -> Result<(), Error> {
+        if self.strict && self.reseed_counter > ctr_drbg::RESEED_INTERVAL {
+            return Err(Error::ReseedRequired);
+        }
+        if self.strict && length > ctr_drbg::MAX_BYTES_PER_REQUEST {
+            return Err(Error::RequestTooLarge);
+        }
+
+        let seed_length = C::key_size() + V_LENGTH;
+        assert!(
+            additional_input.len() <= seed_length,
+            "additional input too long"
+        );
+        let mut additional_input_block = [0; SEED_LENGTH];
+        let mut additional_input_block =
+            ZeroizeGuard::new(&mut additional_input_block[..seed_length]);
+        additional_input_block[..additional_input.len()].copy_from_slice(additional_input);
+
+        let key = &mut self.key[..C::key_size()];
+        if !additional_input.is_empty() {
+            drbg::update::<C>(key, &mut self.v, &additional_input_block);
+        }
+        let skip_blocks = match self.compat_mode {
+            CompatMode::Reference => 0,
+            CompatMode::AlignedExtraBlock => usize::from(length % V_LENGTH == 0),
+        };
+        generate(key, &mut self.v, &additional_input_block, skip_blocks);
+        self.reseed_counter += 1;
+        self.position += length as u64;
+        self.last_request_length = length;
+        Ok(())
     }
 }
 
-impl From<[u8; SEED_LENGTH]> for NistPqcAes256CtrRng {
-    fn from(value: [u8; SEED_LENGTH]) -> Self {
+impl<C, const N: usize> From<[u8; N]> for SeededCtrDrbg<C>
+where
+    C: CtrDrbgCipher,
+    C::Seed: From<[u8; N]>,
+{
+    fn from(value: [u8; N]) -> Self {
         Self::from_seed(value.into())
     }
 }
 
-impl From<&[u8; SEED_LENGTH]> for NistPqcAes256CtrRng {
-    fn from(value: &[u8; SEED_LENGTH]) -> Self {
+impl<C, const N: usize> From<&[u8; N]> for SeededCtrDrbg<C>
+where
+    C: CtrDrbgCipher,
+    C::Seed: From<[u8; N]>,
+{
+    fn from(value: &[u8; N]) -> Self {
         Self::from(*value)
     }
 }
 
-impl TryFrom<&[u8]> for NistPqcAes256CtrRng {
-    type Error = ();
+impl<C> TryFrom<&[u8]> for SeededCtrDrbg<C>
+where
+    C: CtrDrbgCipher,
+    C::Seed: for<'a> TryFrom<&'a [u8], Error = InvalidSeedLength>,
+{
+    type Error = InvalidSeedLength;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        Seed::try_from(value).map(Self::from_seed)
+        C::Seed::try_from(value).map(Self::from_seed)
     }
 }
 
-impl RngCore for NistPqcAes256CtrRng {
+impl<C: CtrDrbgCipher> RngCore for SeededCtrDrbg<C> {
     fn next_u32(&mut self) -> u32 {
         let mut buf = [0; 4];
         self.fill_bytes(&mut buf);
@@ -164,38 +1261,86 @@ impl RngCore for NistPqcAes256CtrRng {
     }
 
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        let mut cipher = Aes256Ctr::new(
-            GenericArray::from_slice(&self.key),
-            GenericArray::from_slice(&self.v),
-        );
-        cipher.seek(16);
-        cipher.apply_keystream(dest);
-        cipher.seek((cipher.current_pos::<usize>() + (V_LENGTH - 1)) / V_LENGTH * V_LENGTH);
-
-        let mut key = [0; KEY_LENGTH];
-        let mut v = [0; V_LENGTH];
-        cipher.apply_keystream(&mut key);
-        cipher.apply_keystream(&mut v);
-        self.key = key;
-        self.v = v;
+        self.fill_bytes_with_additional_input(dest, &[]);
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
-        self.fill_bytes(dest);
-        Ok(())
+        self.try_generate(dest, &[]).map_err(Into::into)
     }
 }
 
-impl CryptoRng for NistPqcAes256CtrRng {}
+impl<C: CtrDrbgCipher> CryptoRng for SeededCtrDrbg<C> {}
 
-#[cfg(test)]
-mod test {
-    use rand_core::{RngCore, SeedableRng};
+/// Reads fill the whole buffer with a single request, i.e., reading `n` bytes
+/// is equivalent to [fill_bytes](RngCore::fill_bytes) with a buffer of `n`
+/// bytes. Requests rejected in strict mode are reported as
+/// [ErrorKind::Other](std::io::ErrorKind::Other).
+#[cfg(feature = "std")]
+impl<C: CtrDrbgCipher> std::io::Read for SeededCtrDrbg<C> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.try_generate(buf, &[]).map_err(std::io::Error::other)?;
+        Ok(buf.len())
+    }
+}
 
-    use super::*;
+/// Iterator drawing seeds from a master RNG
+///
+/// This adapter reproduces the loop of `PQCgenKAT` drawing one seed per KAT
+/// count from the master RNG:
+/// ```
+/// use nist_pqc_seeded_rng::{NistPqcAes256CtrRng, SeedableRng, SubSeeds};
+///
+/// let entropy_input: [u8; 48] = core::array::from_fn(|i| i as u8);
+/// let mut master = NistPqcAes256CtrRng::from(entropy_input);
+/// for (count, seed) in SubSeeds::new(&mut master).take(100).enumerate() {
+///     let rng = NistPqcAes256CtrRng::from_seed(seed);
+///     // produce the KAT for `count` using `rng`
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SubSeeds<'a, R: RngCore + ?Sized> {
+    rng: &'a mut R,
+}
 
-    #[test]
-    fn test_all_zeros() {
+impl<'a, R: RngCore + ?Sized> SubSeeds<'a, R> {
+    /// Create a new iterator drawing seeds from `rng`.
+    pub fn new(rng: &'a mut R) -> Self {
+        Self { rng }
+    }
+}
+
+impl<R: RngCore + ?Sized> Iterator for SubSeeds<'_, R> {
+    type Item = Seed;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(Seed::generate(self.rng))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<R: RngCore + ?Sized> core::iter::FusedIterator for SubSeeds<'_, R> {}
+
+/// Fill `out` with the output of [NistPqcAes256CtrRng] instantiated from `seed`
+///
+/// This is a shortcut for instantiating the RNG and requesting `out.len()`
+/// bytes with a single call to [RngCore::fill_bytes]. With the `zeroize`
+/// feature enabled, the state of the RNG is zeroized afterwards.
+pub fn expand_seed(seed: &Seed, out: &mut [u8]) {
+    let mut rng = NistPqcAes256CtrRng::from_seed(Seed::from(seed.0));
+    rng.fill_bytes(out);
+}
+
+#[cfg(test)]
+mod test {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_all_zeros() {
         let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
         assert_eq!(
             rng.key,
@@ -254,6 +1399,530 @@ mod test {
         assert_eq!(buf, [0xf9, 0xc1, 0x29, 0x94]);
     }
 
+    #[test]
+    fn fill_bytes_overwrites() {
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut buf_1 = [0; 32];
+        let mut buf_2 = [0xff; 32];
+        rng_1.fill_bytes(&mut buf_1);
+        rng_2.fill_bytes(&mut buf_2);
+        assert_eq!(buf_1, buf_2);
+    }
+
+    #[test]
+    fn reseed() {
+        let mut entropy_input = Seed::default();
+        entropy_input
+            .as_mut()
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, byte)| *byte = i as u8);
+        let additional_input: [u8; 16] = core::array::from_fn(|i| 0x80 + i as u8);
+
+        let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut buf = [0; 32];
+        rng.fill_bytes(&mut buf);
+        rng.reseed(&entropy_input, Some(&additional_input));
+        rng.fill_bytes(&mut buf);
+        assert_eq!(
+            buf,
+            [
+                0xb7, 0x7f, 0x5b, 0x6b, 0x1b, 0xef, 0xe9, 0x74, 0xe2, 0x91, 0xef, 0x83, 0x8e, 0xd9,
+                0x89, 0xe3, 0xf0, 0x59, 0xbf, 0xa6, 0x46, 0x3c, 0xd1, 0x72, 0x81, 0x7d, 0x6a, 0xfb,
+                0xc4, 0x5a, 0xdf, 0x89,
+            ]
+        );
+        rng.reseed(&entropy_input, None);
+        let mut buf = [0; 16];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(
+            buf,
+            [
+                0xc5, 0xc4, 0x8b, 0x64, 0x73, 0x43, 0x6a, 0x68, 0x9f, 0x03, 0xaf, 0x99, 0xb5, 0x41,
+                0x59, 0x5a,
+            ]
+        );
+    }
+
+    #[test]
+    fn additional_input() {
+        let additional_input: [u8; 16] = core::array::from_fn(|i| 0x80 + i as u8);
+        let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut buf = [0; 32];
+        rng.fill_bytes_with_additional_input(&mut buf, &additional_input);
+        assert_eq!(
+            buf,
+            [
+                0xc9, 0xd4, 0x1e, 0xd4, 0x2f, 0xbe, 0x78, 0x00, 0xc2, 0xfd, 0x59, 0x22, 0x5b, 0xb3,
+                0xef, 0x75, 0xd0, 0x6f, 0x8c, 0x16, 0x9f, 0x31, 0x46, 0x00, 0x73, 0x41, 0x5a, 0x1d,
+                0x19, 0x23, 0xf0, 0x76,
+            ]
+        );
+        let mut buf = [0; 16];
+        rng.fill_bytes_with_additional_input(&mut buf, &[]);
+        assert_eq!(
+            buf,
+            [
+                0x9f, 0x23, 0xfb, 0xa1, 0x05, 0x4e, 0x27, 0xf2, 0x92, 0x15, 0x31, 0xf0, 0x5e, 0x90,
+                0x47, 0x26,
+            ]
+        );
+        let additional_input: [u8; 48] = core::array::from_fn(|i| i as u8);
+        rng.fill_bytes_with_additional_input(&mut buf, &additional_input);
+        assert_eq!(
+            buf,
+            [
+                0xa6, 0xf0, 0x0b, 0x92, 0x30, 0x4d, 0xf1, 0x5d, 0xf9, 0xfa, 0xbc, 0x36, 0xf6, 0xcd,
+                0x7d, 0xea,
+            ]
+        );
+    }
+
+    #[test]
+    fn reseed_interval() {
+        let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+        assert_eq!(rng.reseed_counter(), 1);
+        let mut buf = [0; 16];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(rng.reseed_counter(), 2);
+        rng.reseed_counter = ctr_drbg::RESEED_INTERVAL + 1;
+        assert!(rng.try_fill_bytes(&mut buf).is_ok());
+
+        let mut rng = NistPqcAes256CtrRng::from_seed_strict(Seed::default());
+        rng.reseed_counter = ctr_drbg::RESEED_INTERVAL;
+        assert!(rng.try_fill_bytes(&mut buf).is_ok());
+        assert!(rng.try_fill_bytes(&mut buf).is_err());
+        rng.reseed(&Seed::default(), None);
+        assert_eq!(rng.reseed_counter(), 1);
+        assert!(rng.try_fill_bytes(&mut buf).is_ok());
+    }
+
+    #[test]
+    fn request_limit() {
+        let mut buf = [0; ctr_drbg::MAX_BYTES_PER_REQUEST + 1];
+        let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+        assert!(rng.try_fill_bytes(&mut buf).is_ok());
+
+        let mut rng = NistPqcAes256CtrRng::from_seed_strict(Seed::default());
+        assert_eq!(rng.try_generate(&mut buf, &[]), Err(Error::RequestTooLarge));
+        assert_eq!(rng.reseed_counter(), 1);
+        assert!(rng
+            .try_fill_bytes(&mut buf[..ctr_drbg::MAX_BYTES_PER_REQUEST])
+            .is_ok());
+    }
+
+    #[test]
+    fn expand() {
+        let mut buf = [0; 12];
+        expand_seed(&Seed::default(), &mut buf);
+        assert_eq!(
+            buf,
+            [0x91, 0x61, 0x8f, 0xe9, 0x9a, 0x8f, 0x94, 0x20, 0x49, 0x7b, 0x24, 0x6f]
+        );
+    }
+
+    fn compat_mode_outputs(compat_mode: CompatMode) -> [u8; 45] {
+        let mut rng = NistPqcAes256CtrRng::from_seed_with_compat_mode(Seed::default(), compat_mode);
+        assert_eq!(rng.compat_mode(), compat_mode);
+        let mut buf = [0; 45];
+        rng.fill_bytes(&mut buf[..16]);
+        rng.fill_bytes(&mut buf[16..32]);
+        rng.fill_bytes(&mut buf[32..37]);
+        rng.fill_bytes(&mut []);
+        rng.fill_bytes(&mut buf[37..]);
+        buf
+    }
+
+    #[test]
+    fn compat_mode_reference() {
+        assert_eq!(
+            compat_mode_outputs(CompatMode::Reference),
+            [
+                0x91, 0x61, 0x8f, 0xe9, 0x9a, 0x8f, 0x94, 0x20, 0x49, 0x7b, 0x24, 0x6f, 0x73, 0x5b,
+                0x27, 0xa0, 0xf9, 0xc1, 0x29, 0x94, 0x8d, 0x41, 0x68, 0x74, 0x78, 0x43, 0x13, 0x62,
+                0xce, 0x74, 0xa0, 0xab, 0x9b, 0x4e, 0x44, 0x1b, 0xd6, 0x87, 0x53, 0xa2, 0x3d, 0xb6,
+                0x26, 0x4f, 0x32,
+            ]
+        );
+    }
+
+    #[test]
+    fn compat_mode_aligned_extra_block() {
+        assert_eq!(
+            compat_mode_outputs(CompatMode::AlignedExtraBlock),
+            [
+                0x91, 0x61, 0x8f, 0xe9, 0x9a, 0x8f, 0x94, 0x20, 0x49, 0x7b, 0x24, 0x6f, 0x73, 0x5b,
+                0x27, 0xa0, 0x79, 0x67, 0x32, 0xac, 0xba, 0x3e, 0xfd, 0xf7, 0x31, 0xbf, 0x7c, 0x24,
+                0x2a, 0xee, 0xdd, 0xf5, 0xd5, 0xfd, 0x28, 0x49, 0x3b, 0x81, 0xa3, 0x11, 0x47, 0xba,
+                0x20, 0x27, 0x01,
+            ]
+        );
+    }
+
+    #[test]
+    fn advance() {
+        for compat_mode in [CompatMode::Reference, CompatMode::AlignedExtraBlock] {
+            for n in [0, 1, 15, 16, 17, 48, 1000] {
+                let mut rng_1 =
+                    NistPqcAes256CtrRng::from_seed_with_compat_mode(Seed::default(), compat_mode);
+                let mut rng_2 =
+                    NistPqcAes256CtrRng::from_seed_with_compat_mode(Seed::default(), compat_mode);
+                rng_1.fill_bytes(&mut [0; 1000][..n]);
+                rng_2.advance(n);
+                assert_eq!(rng_1.reseed_counter(), rng_2.reseed_counter());
+                assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+            }
+        }
+    }
+
+    #[test]
+    fn peek_bytes() {
+        let mut rng = NistAes128CtrRng::from_seed(Aes128Seed::default());
+        rng.next_u32();
+        let reseed_counter = rng.reseed_counter();
+        let mut peeked = [0; 100];
+        rng.peek_bytes(&mut peeked);
+        rng.peek_bytes(&mut peeked[..10]);
+        assert_eq!(rng.reseed_counter(), reseed_counter);
+        let mut buf = [0; 100];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(peeked, buf);
+    }
+
+    #[test]
+    fn position() {
+        let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+        assert_eq!(rng.position(), 0);
+        assert_eq!(rng.last_request_length(), 0);
+        rng.fill_bytes(&mut [0; 5]);
+        rng.next_u64();
+        assert_eq!(rng.position(), 13);
+        assert_eq!(rng.last_request_length(), 8);
+        rng.advance(100);
+        rng.reseed(&Seed::default(), None);
+        assert_eq!(rng.position(), 113);
+        assert_eq!(rng.last_request_length(), 100);
+
+        let mut rng = NistPqcAes256CtrRng::from_seed_strict(Seed::default());
+        assert!(rng
+            .try_fill_bytes(&mut [0; ctr_drbg::MAX_BYTES_PER_REQUEST + 1])
+            .is_err());
+        assert_eq!(rng.position(), 0);
+    }
+
+    #[test]
+    fn from_state() {
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::from([1; 48]));
+        rng_1.next_u32();
+        let mut key = [0; KEY_LENGTH];
+        key.copy_from_slice(rng_1.key());
+        let mut rng_2 = NistPqcAes256CtrRng::from_state(key, *rng_1.v());
+        assert_eq!(rng_2.key(), rng_1.key());
+        assert_eq!(rng_2.v(), rng_1.v());
+        assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+
+        let rng = NistAes128CtrRng::from_seed(Default::default());
+        assert_eq!(rng.key().len(), AES128_KEY_LENGTH);
+    }
+
+    #[test]
+    fn state_bytes() {
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        rng_1.next_u32();
+        let bytes = rng_1.to_state_bytes();
+        assert_eq!(bytes[..KEY_LENGTH], *rng_1.key());
+        assert_eq!(bytes[KEY_LENGTH..], *rng_1.v());
+
+        let mut rng_2 = NistPqcAes256CtrRng::from_state_bytes(bytes);
+        assert_eq!(rng_2.to_state_bytes(), bytes);
+        assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+    }
+
+    #[test]
+    fn const_constructors() {
+        const SEED: Seed = Seed::new([0; SEED_LENGTH]);
+        const STATE: [u8; SEED_LENGTH] = [
+            0x53, 0x0f, 0x8a, 0xfb, 0xc7, 0x45, 0x36, 0xb9, 0xa9, 0x63, 0xb4, 0xf1, 0xc4, 0xcb,
+            0x73, 0x8b, 0xce, 0xa7, 0x40, 0x3d, 0x4d, 0x60, 0x6b, 0x6e, 0x07, 0x4e, 0xc5, 0xd3,
+            0xba, 0xf3, 0x9d, 0x18, 0x72, 0x60, 0x03, 0xca, 0x37, 0xa6, 0x2a, 0x74, 0xd1, 0xa2,
+            0xf5, 0x8e, 0x75, 0x06, 0x35, 0x8e,
+        ];
+        const RNG: NistPqcAes256CtrRng = NistPqcAes256CtrRng::from_state_bytes(STATE);
+
+        let mut rng_1 = RNG;
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(SEED);
+        assert_eq!(rng_1.to_state_bytes(), rng_2.to_state_bytes());
+        assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+    }
+
+    #[test]
+    fn clone() {
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed_strict(Seed::default());
+        rng_1.next_u32();
+        let mut rng_2 = rng_1.clone();
+        let mut buf_1 = [0; 32];
+        let mut buf_2 = [0; 64];
+        rng_1.fill_bytes(&mut buf_1);
+        rng_2.fill_bytes(&mut buf_2);
+        assert_eq!(buf_1, buf_2[..32]);
+        assert_ne!(rng_1.next_u64(), rng_2.next_u64());
+
+        let mut rng_3 = rng_2.clone();
+        assert_eq!(rng_2.to_state_bytes(), rng_3.to_state_bytes());
+        assert_eq!(rng_2.position(), rng_3.position());
+        assert!(rng_3
+            .try_fill_bytes(&mut [0; ctr_drbg::MAX_BYTES_PER_REQUEST + 1])
+            .is_err());
+    }
+
+    #[test]
+    fn gen_array() {
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let coins: [u8; 32] = rng_1.gen_array();
+        let mut expected = [0; 32];
+        rng_2.fill_bytes(&mut expected);
+        assert_eq!(coins, expected);
+        assert_eq!(rng_1.gen_array::<3>(), rng_2.gen_array::<3>());
+        assert_eq!(rng_1.reseed_counter(), 3);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn generate_vec() {
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let vec = rng_1.generate_vec(100);
+        let mut expected = [0; 100];
+        rng_2.fill_bytes(&mut expected);
+        assert_eq!(vec, expected);
+        assert!(rng_1.generate_vec(0).is_empty());
+        assert_eq!(rng_1.reseed_counter(), 3);
+    }
+
+    #[test]
+    fn fill_uninit() {
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut buf = [MaybeUninit::uninit(); 2500];
+        let mut expected = [0; 2500];
+        rng_2.fill_bytes(&mut expected);
+        assert_eq!(rng_1.fill_uninit(&mut buf), expected);
+        assert!(rng_1.fill_uninit(&mut []).is_empty());
+        assert_eq!(rng_1.reseed_counter(), 3);
+        assert_eq!(rng_1.next_u64(), {
+            rng_2.fill_bytes(&mut []);
+            rng_2.next_u64()
+        });
+    }
+
+    #[test]
+    fn next_u128() {
+        let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+        assert_eq!(rng.next_u128(), 0xa0275b736f247b4920948f9ae98f6191);
+        assert_eq!(rng.reseed_counter(), 2);
+    }
+
+    #[test]
+    fn fill_word_slices() {
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut words = [0; 37];
+        rng_1.fill_u32_slice(&mut words);
+        let mut bytes = [0; 4 * 37];
+        rng_2.fill_bytes(&mut bytes);
+        for (word, bytes) in words.iter().zip(bytes.chunks_exact(4)) {
+            assert_eq!(*word, u32::from_le_bytes(bytes.try_into().unwrap()));
+        }
+        assert_eq!(words[0], 0xe98f6191);
+
+        let mut words = [0; 19];
+        rng_1.fill_u64_slice(&mut words);
+        let mut bytes = [0; 8 * 19];
+        rng_2.fill_bytes(&mut bytes);
+        for (word, bytes) in words.iter().zip(bytes.chunks_exact(8)) {
+            assert_eq!(*word, u64::from_le_bytes(bytes.try_into().unwrap()));
+        }
+        assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+    }
+
+    #[test]
+    fn fork() {
+        let mut parent_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut parent_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut child_1 = parent_1.fork(b"child");
+        let mut child_2 = parent_2.fork(b"child");
+        assert_eq!(child_1.next_u64(), child_2.next_u64());
+        assert_eq!(parent_1.next_u64(), parent_2.next_u64());
+
+        let mut child_3 = parent_1.fork(b"child");
+        let mut child_4 = parent_2.fork(b"other child");
+        assert_ne!(child_1.next_u64(), child_3.next_u64());
+        assert_ne!(child_3.next_u64(), child_4.next_u64());
+
+        let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut seed = [0; SEED_LENGTH];
+        rng.fill_bytes(&mut seed);
+        let mut label_block = [0; SEED_LENGTH];
+        ctr_drbg::block_cipher_df(SecurityStrength::Bits256, b"child", &mut label_block).unwrap();
+        seed.iter_mut().zip(label_block).for_each(|(x, y)| *x ^= y);
+        let mut child = NistPqcAes256CtrRng::from(seed);
+        let mut parent = NistPqcAes256CtrRng::from_seed(Seed::default());
+        assert_eq!(parent.fork(b"child").next_u64(), child.next_u64());
+    }
+
+    #[test]
+    fn fork_block_boundary_labels() {
+        let label: [u8; 39] = core::array::from_fn(|i| 0x40 + i as u8);
+        for (length, expected) in [
+            (7, 0x7f8e54bcf06cab17),
+            (23, 0x76b267608747bf46),
+            (39, 0x0c9a5aeca9dca396),
+        ] {
+            let mut parent = NistPqcAes256CtrRng::from_seed(Seed::default());
+            assert_eq!(parent.fork(&label[..length]).next_u64(), expected);
+        }
+    }
+
+    #[test]
+    fn sub_seeds() {
+        let entropy_input: [u8; 48] = core::array::from_fn(|i| i as u8);
+        let mut master = NistPqcAes256CtrRng::from(entropy_input);
+        let mut sub_seeds = SubSeeds::new(&mut master);
+        assert_eq!(
+            sub_seeds.next().unwrap().as_ref(),
+            [
+                0x06, 0x15, 0x50, 0x23, 0x4d, 0x15, 0x8c, 0x5e, 0xc9, 0x55, 0x95, 0xfe, 0x04, 0xef,
+                0x7a, 0x25, 0x76, 0x7f, 0x2e, 0x24, 0xcc, 0x2b, 0xc4, 0x79, 0xd0, 0x9d, 0x86, 0xdc,
+                0x9a, 0xbc, 0xfd, 0xe7, 0x05, 0x6a, 0x8c, 0x26, 0x6f, 0x9e, 0xf9, 0x7e, 0xd0, 0x85,
+                0x41, 0xdb, 0xd2, 0xe1, 0xff, 0xa1,
+            ]
+        );
+        assert_eq!(
+            sub_seeds.next().unwrap().as_ref(),
+            [
+                0xd8, 0x1c, 0x4d, 0x8d, 0x73, 0x4f, 0xcb, 0xfb, 0xea, 0xde, 0x3d, 0x3f, 0x8a, 0x03,
+                0x9f, 0xaa, 0x2a, 0x2c, 0x99, 0x57, 0xe8, 0x35, 0xad, 0x55, 0xb2, 0x2e, 0x75, 0xbf,
+                0x57, 0xbb, 0x55, 0x6a, 0xc8, 0x1a, 0xdd, 0xe6, 0xae, 0xeb, 0x4a, 0x5a, 0x87, 0x5c,
+                0x3b, 0xfc, 0xad, 0xfa, 0x95, 0x8f,
+            ]
+        );
+    }
+
+    #[test]
+    fn seed_from_u64() {
+        for state in [0, 1, u64::MAX] {
+            let mut rng_1 = NistPqcAes256CtrRng::seed_from_u64(state);
+            let mut rng_2 = CtrDrbg::new(&state.to_le_bytes(), &[]);
+            let mut rng_3 = CtrDrbg::seed_from_u64(state);
+            let value = rng_1.next_u64();
+            assert_eq!(value, rng_2.next_u64());
+            assert_eq!(value, rng_3.next_u64());
+        }
+
+        let mut rng_1 = NistAes128CtrRng::seed_from_u64(1);
+        let mut rng_2 =
+            CtrDrbg::with_security_strength(&1u64.to_le_bytes(), &[], SecurityStrength::Bits128);
+        assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+    }
+
+    #[test]
+    fn aes128_all_zeros() {
+        let mut rng = NistAes128CtrRng::from_seed(Aes128Seed::default());
+        assert_eq!(
+            rng.key[..AES128_KEY_LENGTH],
+            [
+                0x58, 0xe2, 0xfc, 0xce, 0xfa, 0x7e, 0x30, 0x61, 0x36, 0x7f, 0x1d, 0x57, 0xa4, 0xe7,
+                0x45, 0x5a,
+            ]
+        );
+        let mut buf = [0; 8];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf, [0xd4, 0x0e, 0x25, 0xd3, 0x86, 0xf0, 0x68, 0xba]);
+        let mut buf = [0; 4];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf, [0xbc, 0x6f, 0x12, 0xb1]);
+    }
+
+    #[test]
+    fn aes128() {
+        let mut seed = [0; AES128_SEED_LENGTH];
+        seed.iter_mut()
+            .enumerate()
+            .for_each(|(i, byte)| *byte = i as u8);
+        let mut rng = NistAes128CtrRng::from(seed);
+        let mut buf = [0; 32];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(
+            buf,
+            [
+                0x16, 0x86, 0xff, 0xcf, 0x9f, 0x35, 0x8b, 0xe7, 0x44, 0x52, 0xe6, 0x47, 0xba, 0x15,
+                0x6a, 0xab, 0x05, 0x13, 0x57, 0x97, 0x11, 0x7f, 0xd1, 0xab, 0x31, 0x7d, 0x31, 0x8c,
+                0x66, 0x0e, 0x3d, 0x18,
+            ]
+        );
+        let mut buf = [0; 17];
+        rng.fill_bytes(&mut buf[..16]);
+        rng.fill_bytes(&mut buf[..1]);
+        rng.fill_bytes(&mut buf);
+        assert_eq!(
+            buf,
+            [
+                0xa4, 0x2d, 0x94, 0x70, 0x6e, 0x7d, 0xd9, 0x57, 0x1b, 0xbe, 0x00, 0x81, 0x11, 0xac,
+                0xd8, 0x87, 0x94,
+            ]
+        );
+    }
+
+    #[test]
+    fn aes192_all_zeros() {
+        let mut rng = NistAes192CtrRng::from_seed(Aes192Seed::default());
+        assert_eq!(
+            rng.key[..AES192_KEY_LENGTH],
+            [
+                0xcd, 0x33, 0xb2, 0x8a, 0xc7, 0x73, 0xf7, 0x4b, 0xa0, 0x0e, 0xd1, 0xf3, 0x12, 0x57,
+                0x24, 0x35, 0x98, 0xe7, 0x24, 0x7c, 0x07, 0xf0, 0xfe, 0x41,
+            ]
+        );
+        let mut buf = [0; 8];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf, [0x77, 0xc8, 0x56, 0xed, 0xbc, 0xf2, 0x1d, 0x54]);
+        let mut buf = [0; 4];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf, [0x27, 0x8f, 0x25, 0x52]);
+    }
+
+    #[test]
+    fn aes192() {
+        let mut seed = [0; AES192_SEED_LENGTH];
+        seed.iter_mut()
+            .enumerate()
+            .for_each(|(i, byte)| *byte = i as u8);
+        let mut rng = NistAes192CtrRng::try_from(seed.as_slice()).expect("seed of invalid length");
+        let mut buf = [0; 32];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(
+            buf,
+            [
+                0x01, 0xe0, 0x79, 0x3e, 0x6c, 0x74, 0x64, 0xfa, 0xfe, 0x1f, 0x6c, 0xf9, 0xb7, 0x46,
+                0x6a, 0x8a, 0xc4, 0x84, 0x17, 0x37, 0x9c, 0xba, 0xa1, 0x04, 0x13, 0xdb, 0xcd, 0x98,
+                0xe1, 0x97, 0x70, 0x19,
+            ]
+        );
+        let mut buf = [0; 17];
+        rng.fill_bytes(&mut buf[..16]);
+        rng.fill_bytes(&mut buf[..1]);
+        rng.fill_bytes(&mut buf);
+        assert_eq!(
+            buf,
+            [
+                0x0d, 0x10, 0x5d, 0x0f, 0xc1, 0x06, 0x2c, 0x7e, 0x77, 0x8b, 0x8c, 0x03, 0x6d, 0x37,
+                0x17, 0x0d, 0x3e,
+            ]
+        );
+    }
+
     #[test]
     fn from() {
         let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
@@ -269,4 +1938,433 @@ mod test {
         assert_eq!(rng.v, rng_1.v);
         assert_eq!(rng.v, rng_2.v);
     }
+
+    #[test]
+    fn seed_from_hex() {
+        let hex = "061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7056A8C266F9EF97ED08541DBD2E1FFA1";
+        let seed = Seed::from_hex(hex).unwrap();
+        assert_eq!(seed[..4], [0x06, 0x15, 0x50, 0x23]);
+        assert_eq!(seed[44..], [0xd2, 0xe1, 0xff, 0xa1]);
+        assert_eq!(hex.to_ascii_lowercase().parse::<Seed>().unwrap().0, seed.0);
+        assert_eq!(
+            Seed::from_hex(&hex[..94]).unwrap_err(),
+            ParseSeedError::InvalidLength {
+                expected: 96,
+                actual: 94
+            }
+        );
+        assert!(Aes128Seed::from_hex(hex).is_err());
+        assert!(Aes128Seed::from_hex(&hex[..64]).is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn seed_hex_formatting() {
+        use std::format;
+
+        let hex = "061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7056A8C266F9EF97ED08541DBD2E1FFA1";
+        let seed = Seed::from_hex(hex).unwrap();
+        assert_eq!(format!("{:X}", seed), hex);
+        assert_eq!(format!("{}", seed), hex);
+        assert_eq!(format!("{:x}", seed), hex.to_ascii_lowercase());
+        assert_eq!(format!("{:x}", Aes128Seed::default()), "0".repeat(64));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_read() {
+        use std::io::Read;
+
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut buf = [0; 100];
+        rng_1.read_exact(&mut buf).unwrap();
+        let mut expected = [0; 100];
+        rng_2.fill_bytes(&mut expected);
+        assert_eq!(buf, expected);
+        assert_eq!(rng_1.reseed_counter(), 2);
+
+        let mut rng = NistPqcAes256CtrRng::from_seed_strict(Seed::default());
+        let err = rng
+            .read(&mut [0; ctr_drbg::MAX_BYTES_PER_REQUEST + 1])
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::Other);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_hex_to() {
+        use std::{format, string::String};
+
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut hex = String::new();
+        rng_1.write_hex_to(&mut hex, 100).unwrap();
+        let mut expected = [0; 100];
+        rng_2.fill_bytes(&mut expected);
+        let expected: String = expected.iter().map(|b| format!("{:02X}", b)).collect();
+        assert_eq!(hex, expected);
+        assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn seed_base64() {
+        let seed = Seed::from_hex(
+            "061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7056A8C266F9EF97ED08541DBD2E1FFA1",
+        )
+        .unwrap();
+        let base64 = "BhVQI00VjF7JVZX+BO96JXZ/LiTMK8R50J2G3Jq8/ecFaowmb575ftCFQdvS4f+h";
+        assert_eq!(seed.to_base64(), base64);
+        assert_eq!(Seed::from_base64(base64).unwrap().0, seed.0);
+        assert_eq!(
+            Seed::from_base64(&base64[..60]).unwrap_err(),
+            ParseSeedError::InvalidLength {
+                expected: 64,
+                actual: 60
+            }
+        );
+        assert_eq!(
+            alloc::string::ToString::to_string(&Seed::from_base64(&base64[..60]).unwrap_err()),
+            "string of invalid length: expected 64 characters, got 60"
+        );
+        assert_eq!(
+            Seed::from_base64(&base64.replace('+', "-")).unwrap_err(),
+            ParseSeedError::InvalidBase64
+        );
+
+        let seed = Aes128Seed::from([0xff; AES128_SEED_LENGTH]);
+        assert_eq!(
+            Aes128Seed::from_base64(&seed.to_base64()).unwrap().0,
+            seed.0
+        );
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn seed_random() {
+        let seed_1 = Seed::random().unwrap();
+        let seed_2 = Seed::random().unwrap();
+        assert_ne!(seed_1.0, seed_2.0);
+        assert_ne!(Aes128Seed::random().unwrap().0, [0; AES128_SEED_LENGTH]);
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn from_entropy() {
+        let mut rng_1 = NistPqcAes256CtrRng::from_entropy();
+        let mut rng_2 = NistPqcAes256CtrRng::from_entropy();
+        assert_ne!(rng_1.next_u64(), rng_2.next_u64());
+        CtrDrbg::from_entropy().next_u32();
+        buffered::WordBufferedRng::<Aes128>::from_entropy().next_u32();
+        ContinuousMode::<Aes256>::from_entropy().next_u32();
+        NistSeedExpander::from_entropy().next_u32();
+        #[cfg(feature = "chacha")]
+        ChaCha20SeededRng::from_entropy().next_u32();
+        #[cfg(feature = "hash-drbg")]
+        HashDrbgSha256::from_entropy().next_u32();
+        #[cfg(feature = "hmac-drbg")]
+        HmacDrbgSha256::from_entropy().next_u32();
+        #[cfg(feature = "shake")]
+        {
+            Shake128Rng::from_entropy().next_u32();
+            Shake256Rng::from_entropy().next_u32();
+        }
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn from_os_entropy() {
+        let (mut rng_1, seed) = NistAes128CtrRng::from_os_entropy().unwrap();
+        let mut rng_2 = NistAes128CtrRng::from_seed(seed);
+        assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+    }
+
+    #[test]
+    fn seed_generate() {
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let seed = Seed::generate(&mut rng_1);
+        let mut expected = [0; SEED_LENGTH];
+        rng_2.fill_bytes(&mut expected);
+        assert_eq!(seed.0, expected);
+
+        let rng: &mut dyn RngCore = &mut rng_1;
+        let seed = Aes128Seed::generate(rng);
+        let mut expected = [0; AES128_SEED_LENGTH];
+        rng_2.fill_bytes(&mut expected);
+        assert_eq!(seed.0, expected);
+    }
+
+    #[cfg(feature = "shake")]
+    #[test]
+    fn seed_from_passphrase() {
+        assert_eq!(
+            Seed::from_passphrase("correct horse battery staple").0,
+            [
+                0xe9, 0x9e, 0x1d, 0xa0, 0x83, 0x6e, 0xb1, 0x08, 0x9a, 0xbc, 0x13, 0x5f, 0x71, 0x81,
+                0xaa, 0x31, 0x8f, 0x1c, 0x88, 0xe6, 0x87, 0x4d, 0xeb, 0xbd, 0xab, 0xa7, 0x4a, 0xd0,
+                0xec, 0x95, 0xa1, 0xdf, 0xb4, 0x17, 0xb5, 0x5f, 0xfc, 0xb8, 0x06, 0x1c, 0x8d, 0xf3,
+                0xac, 0xbb, 0x20, 0xbe, 0xce, 0xb6,
+            ]
+        );
+        assert_eq!(
+            Aes128Seed::from_passphrase("").0,
+            [
+                0x46, 0xb9, 0xdd, 0x2b, 0x0b, 0xa8, 0x8d, 0x13, 0x23, 0x3b, 0x3f, 0xeb, 0x74, 0x3e,
+                0xeb, 0x24, 0x3f, 0xcd, 0x52, 0xea, 0x62, 0xb8, 0x1b, 0x82, 0xb5, 0x0c, 0x27, 0x64,
+                0x6e, 0xd5, 0x76, 0x2f,
+            ]
+        );
+    }
+
+    #[test]
+    fn seed_index_mut() {
+        let mut seed = Seed::default();
+        for i in 0..SEED_LENGTH {
+            seed[i] = i as u8;
+        }
+        seed[..2].copy_from_slice(&[0xff; 2]);
+        assert_eq!(seed[..4], [0xff, 0xff, 2, 3]);
+        assert_eq!(seed[SEED_LENGTH - 1], 0x2f);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn seed_from_alloc() {
+        use alloc::{boxed::Box, vec::Vec};
+
+        let bytes: [u8; SEED_LENGTH] = core::array::from_fn(|i| i as u8);
+        assert_eq!(Seed::try_from(Vec::from(bytes)).unwrap().0, bytes);
+        assert_eq!(Seed::from(Box::new(bytes)).0, bytes);
+        assert_eq!(
+            Seed::try_from(Vec::from(&bytes[..40])).unwrap_err(),
+            InvalidSeedLength {
+                expected: SEED_LENGTH,
+                actual: 40
+            }
+        );
+        assert!(Aes192Seed::try_from(Vec::from(&bytes[..40])).is_ok());
+    }
+
+    #[test]
+    fn seed_split_join() {
+        let seed = Seed::from(core::array::from_fn(|i| i as u8));
+        let (key, v) = seed.split();
+        assert_eq!(key[..], seed[..KEY_LENGTH]);
+        assert_eq!(v[..], seed[KEY_LENGTH..]);
+        assert_eq!(Seed::join(key, v).0, seed.0);
+
+        let seed = Aes128Seed::from(core::array::from_fn(|i| i as u8));
+        let (key, v) = seed.split();
+        assert_eq!(key.len(), AES128_KEY_LENGTH);
+        assert_eq!(v[0], AES128_KEY_LENGTH as u8);
+        assert_eq!(Aes128Seed::join(key, v).0, seed.0);
+    }
+
+    #[test]
+    fn seed_macro() {
+        let hex = "061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7056A8C266F9EF97ED08541DBD2E1FFA1";
+        let seed = seed!("061550234d158c5ec95595fe04ef7a25767f2e24cc2bc479d09d86dc9abcfde7056a8c266f9ef97ed08541dbd2e1ffa1");
+        assert_eq!(seed.0, Seed::from_hex(hex).unwrap().0);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn seed_bytemuck() {
+        use bytemuck::{TransparentWrapper, Zeroable};
+
+        assert_eq!(Seed::zeroed().0, [0; SEED_LENGTH]);
+        let mut bytes: [u8; SEED_LENGTH] = core::array::from_fn(|i| i as u8);
+        let seed = Seed::wrap_ref(&bytes);
+        assert_eq!(seed[..], bytes[..]);
+        Seed::wrap_mut(&mut bytes)[0] = 0xff;
+        assert_eq!(bytes[0], 0xff);
+        let seeds = Aes128Seed::wrap_slice(&[[1; AES128_SEED_LENGTH]; 2]);
+        assert_eq!(seeds[1][..], [1; AES128_SEED_LENGTH]);
+    }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn constant_time_eq() {
+        use subtle::ConstantTimeEq;
+
+        let seed = Seed::from([1; SEED_LENGTH]);
+        assert!(bool::from(seed.ct_eq(&Seed::from([1; SEED_LENGTH]))));
+        assert!(!bool::from(seed.ct_eq(&Seed::default())));
+
+        let mut rng_1 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_2 = NistPqcAes256CtrRng::from_seed(Seed::default());
+        assert!(bool::from(rng_1.ct_eq(&rng_2)));
+        rng_1.next_u32();
+        assert!(!bool::from(rng_1.ct_eq(&rng_2)));
+        rng_2.next_u64();
+        assert!(bool::from(rng_1.ct_eq(&rng_2)));
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::Zeroize;
+
+        let mut seed = Seed::from([1; SEED_LENGTH]);
+        seed.zeroize();
+        assert_eq!(seed.0, [0; SEED_LENGTH]);
+
+        let mut rng = NistPqcAes256CtrRng::from_seed_with_compat_mode(
+            Seed::default(),
+            CompatMode::AlignedExtraBlock,
+        );
+        rng.next_u32();
+        rng.zeroize();
+        assert_eq!(rng.to_state_bytes(), [0; SEED_LENGTH]);
+        assert_eq!(rng.reseed_counter(), 1);
+        assert_eq!(rng.position(), 0);
+        assert_eq!(rng.last_request_length(), 0);
+        assert_eq!(rng.compat_mode(), CompatMode::AlignedExtraBlock);
+        let mut expected = NistPqcAes256CtrRng::from_state([0; KEY_LENGTH], [0; V_LENGTH]);
+        expected.compat_mode = CompatMode::AlignedExtraBlock;
+        let mut buf = [0; 32];
+        let mut expected_buf = [0; 32];
+        rng.fill_bytes(&mut buf);
+        expected.fill_bytes(&mut expected_buf);
+        assert_eq!(buf, expected_buf);
+
+        let mut rng = NistPqcAes256CtrRng::from_seed_strict(Seed::default());
+        rng.next_u32();
+        rng.zeroize();
+        assert!(rng.strict);
+        assert_eq!(rng.reseed_counter(), 1);
+    }
+
+    #[test]
+    fn seed_xor() {
+        let bytes: [u8; SEED_LENGTH] = core::array::from_fn(|i| i as u8);
+        let mut seed = Seed::from([0xff; SEED_LENGTH]);
+        let xored = seed.xored(&bytes);
+        seed.xor_with(&bytes);
+        assert_eq!(seed.0, xored.0);
+        assert_eq!(seed[..2], [0xff, 0xfe]);
+        assert_eq!(seed.xored(&bytes).0, [0xff; SEED_LENGTH]);
+    }
+
+    #[test]
+    fn seed_n() {
+        let bytes: [u8; 64] = core::array::from_fn(|i| i as u8);
+        let seed = SeedN::<64>::try_from(&bytes[..]).unwrap();
+        assert_eq!(seed[..], bytes[..]);
+        assert_eq!(
+            SeedN::<64>::try_from(&bytes[..48]).unwrap_err(),
+            InvalidSeedLength {
+                expected: 64,
+                actual: 48
+            }
+        );
+
+        let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let seed = SeedN::<100>::generate(&mut rng);
+        assert_ne!(seed.0, [0; 100]);
+    }
+
+    #[test]
+    fn seed_into_inner() {
+        let bytes: [u8; SEED_LENGTH] = core::array::from_fn(|i| i as u8);
+        let seed = Seed::from(bytes);
+        let array: &[u8; SEED_LENGTH] = seed.as_ref();
+        assert_eq!(array, &bytes);
+        assert_eq!(seed.into_inner(), bytes);
+        assert_eq!(<[u8; SEED_LENGTH]>::from(Seed::from(bytes)), bytes);
+    }
+
+    #[test]
+    fn invalid_seed_length() {
+        let err = NistPqcAes256CtrRng::try_from(&[0; 32][..]).unwrap_err();
+        assert_eq!(
+            err,
+            InvalidSeedLength {
+                expected: SEED_LENGTH,
+                actual: 32
+            }
+        );
+        assert_eq!(
+            Aes128Seed::try_from(&[0; 48][..]).unwrap_err(),
+            InvalidSeedLength {
+                expected: AES128_SEED_LENGTH,
+                actual: 48
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_key_and_v() {
+        use serde_test::{assert_de_tokens, Token};
+
+        #[derive(Debug, serde::Deserialize)]
+        #[serde(transparent)]
+        struct Wrapper(NistPqcAes256CtrRng);
+
+        impl PartialEq for Wrapper {
+            fn eq(&self, other: &Self) -> bool {
+                self.0.key == other.0.key
+                    && self.0.v == other.0.v
+                    && self.0.reseed_counter == other.0.reseed_counter
+                    && self.0.position == other.0.position
+                    && self.0.last_request_length == other.0.last_request_length
+                    && self.0.strict == other.0.strict
+                    && self.0.compat_mode == other.0.compat_mode
+            }
+        }
+
+        let mut tokens = [Token::U8(0); 56];
+        tokens[0] = Token::Struct {
+            name: "SeededCtrDrbg",
+            len: 2,
+        };
+        tokens[1] = Token::Str("key");
+        tokens[2] = Token::Tuple { len: KEY_LENGTH };
+        tokens[35] = Token::TupleEnd;
+        tokens[36] = Token::Str("v");
+        tokens[37] = Token::Tuple { len: V_LENGTH };
+        tokens[54] = Token::TupleEnd;
+        tokens[55] = Token::StructEnd;
+        for i in 0..KEY_LENGTH {
+            tokens[3 + i] = Token::U8(i as u8);
+        }
+        for i in 0..V_LENGTH {
+            tokens[38 + i] = Token::U8((KEY_LENGTH + i) as u8);
+        }
+
+        let rng = NistPqcAes256CtrRng::from_state(
+            core::array::from_fn(|i| i as u8),
+            core::array::from_fn(|i| (KEY_LENGTH + i) as u8),
+        );
+        assert_de_tokens(&Wrapper(rng), &tokens);
+    }
+
+    #[cfg(all(feature = "zeroize", feature = "std"))]
+    #[test]
+    fn zeroize_buffers() {
+        // besides the output buffers of 64 bytes, the padded additional input
+        // and the temporary buffer of the update are zeroized
+        guard::take_zeroized();
+        let mut rng = NistPqcAes256CtrRng::from_seed(Seed::default());
+        assert_eq!(guard::take_zeroized(), [SEED_LENGTH]);
+        rng.fill_u32_slice(&mut [0; 20]);
+        assert_eq!(guard::take_zeroized(), [64, SEED_LENGTH, SEED_LENGTH]);
+        rng.write_hex_to(&mut std::string::String::new(), 100)
+            .unwrap();
+        assert_eq!(guard::take_zeroized(), [64, SEED_LENGTH, SEED_LENGTH]);
+        rng.reseed(&Seed::default(), None);
+        assert_eq!(guard::take_zeroized(), [SEED_LENGTH, SEED_LENGTH]);
+    }
+
+    #[test]
+    fn error_types() {
+        fn assert_error<E: core::error::Error>() {}
+
+        assert_error::<Error>();
+        assert_error::<InvalidSeedLength>();
+        assert_error::<ParseSeedError>();
+    }
 }
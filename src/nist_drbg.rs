@@ -0,0 +1,262 @@
+//! Common interface of the RNGs for generic KAT tooling.
+
+use aes::cipher::typenum::Unsigned;
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+use crate::{
+    ctr_drbg, CtrDrbg, CtrDrbgCipher, DynDrbg, Error, Seed, SeededCtrDrbg, KEY_LENGTH, SEED_LENGTH,
+    V_LENGTH,
+};
+
+/// Interface of the RNGs instantiated from a seed of fixed length
+///
+/// Generic KAT tooling can be written over this trait and run with any of the
+/// RNGs:
+/// ```
+/// use nist_pqc_seeded_rng::{Error, NistDrbg, NistPqcAes256CtrRng};
+///
+/// fn first_bytes<R: NistDrbg>(seed: &[u8]) -> Result<[u8; 32], Error> {
+///     let mut rng = R::instantiate(seed)?;
+///     let mut buf = [0; 32];
+///     rng.generate_bytes(&mut buf)?;
+///     Ok(buf)
+/// }
+///
+/// let buf = first_bytes::<NistPqcAes256CtrRng>(&[0; 48]).unwrap();
+/// ```
+pub trait NistDrbg: DrbgParameters + RngCore + CryptoRng + Sized {
+    /// Instantiate the RNG from a seed.
+    ///
+    /// Fails with [Error::InvalidInputLength] if the seed is not exactly
+    /// [SEED_LEN](DrbgParameters::SEED_LEN) bytes long.
+    fn instantiate(seed: &[u8]) -> Result<Self, Error>;
+
+    /// Fill `dest` with random bytes.
+    fn generate_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error>;
+}
+
+/// Parameters of the RNGs
+///
+/// The parameters describe the RNG as instantiated by
+/// [NistDrbg::instantiate].
+pub trait DrbgParameters {
+    /// Length of the seed in bytes.
+    const SEED_LEN: usize;
+    /// Length of the key of the underlying primitive in bytes or 0 if the
+    /// primitive is not keyed.
+    const KEY_LEN: usize;
+    /// Length of the blocks produced by the underlying primitive in bytes.
+    const BLOCK_LEN: usize;
+    /// Maximum number of bytes per request as specified for the RNG or
+    /// [usize::MAX] if unlimited.
+    ///
+    /// Note that [SeededCtrDrbg] only enforces the limit in strict mode.
+    const MAX_REQUEST_LEN: usize;
+}
+
+/// Check the length of the seed and copy it into a [Seed].
+fn seed(seed: &[u8]) -> Result<Seed, Error> {
+    Seed::try_from(seed).map_err(Into::into)
+}
+
+impl<C: CtrDrbgCipher> DrbgParameters for SeededCtrDrbg<C> {
+    const SEED_LEN: usize = C::KeySize::USIZE + V_LENGTH;
+    const KEY_LEN: usize = C::KeySize::USIZE;
+    const BLOCK_LEN: usize = V_LENGTH;
+    const MAX_REQUEST_LEN: usize = ctr_drbg::MAX_BYTES_PER_REQUEST;
+}
+
+impl<C: CtrDrbgCipher> NistDrbg for SeededCtrDrbg<C> {
+    fn instantiate(seed: &[u8]) -> Result<Self, Error> {
+        if seed.len() != Self::SEED_LEN {
+            return Err(Error::InvalidInputLength);
+        }
+        let mut rng_seed = C::Seed::default();
+        rng_seed.as_mut().copy_from_slice(seed);
+        Ok(Self::from_seed(rng_seed))
+    }
+
+    fn generate_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.try_generate(dest, &[])
+    }
+}
+
+impl DrbgParameters for CtrDrbg {
+    const SEED_LEN: usize = SEED_LENGTH;
+    const KEY_LEN: usize = KEY_LENGTH;
+    const BLOCK_LEN: usize = V_LENGTH;
+    const MAX_REQUEST_LEN: usize = ctr_drbg::MAX_BYTES_PER_REQUEST;
+}
+
+impl NistDrbg for CtrDrbg {
+    fn instantiate(seed: &[u8]) -> Result<Self, Error> {
+        self::seed(seed).map(Self::from_seed)
+    }
+
+    fn generate_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill(dest)
+    }
+}
+
+/// The seed length is that of [Seed] for all hash functions, i.e., 256 bits of
+/// entropy input and 128 bits of nonce.
+#[cfg(feature = "hmac-drbg")]
+impl<D> DrbgParameters for crate::HmacDrbg<D>
+where
+    D: hmac::digest::Digest + hmac::digest::core_api::BlockSizeUser,
+{
+    const SEED_LEN: usize = SEED_LENGTH;
+    const KEY_LEN: usize = <D as hmac::digest::OutputSizeUser>::OutputSize::USIZE;
+    const BLOCK_LEN: usize = <D as hmac::digest::OutputSizeUser>::OutputSize::USIZE;
+    const MAX_REQUEST_LEN: usize = crate::hmac_drbg::MAX_BYTES_PER_REQUEST;
+}
+
+#[cfg(feature = "hmac-drbg")]
+impl<D> NistDrbg for crate::HmacDrbg<D>
+where
+    D: hmac::digest::Digest + hmac::digest::core_api::BlockSizeUser,
+{
+    fn instantiate(seed: &[u8]) -> Result<Self, Error> {
+        self::seed(seed).map(Self::from_seed)
+    }
+
+    fn generate_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill(dest)
+    }
+}
+
+#[cfg(feature = "hash-drbg")]
+impl DrbgParameters for crate::HashDrbgSha256 {
+    const SEED_LEN: usize = SEED_LENGTH;
+    const KEY_LEN: usize = 0;
+    const BLOCK_LEN: usize = 32;
+    const MAX_REQUEST_LEN: usize = crate::hash_drbg::MAX_BYTES_PER_REQUEST;
+}
+
+#[cfg(feature = "hash-drbg")]
+impl NistDrbg for crate::HashDrbgSha256 {
+    fn instantiate(seed: &[u8]) -> Result<Self, Error> {
+        self::seed(seed).map(Self::from_seed)
+    }
+
+    fn generate_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill(dest)
+    }
+}
+
+#[cfg(feature = "chacha")]
+impl DrbgParameters for crate::ChaCha20SeededRng {
+    const SEED_LEN: usize = SEED_LENGTH;
+    const KEY_LEN: usize = KEY_LENGTH;
+    const BLOCK_LEN: usize = 64;
+    const MAX_REQUEST_LEN: usize = usize::MAX;
+}
+
+#[cfg(feature = "chacha")]
+impl NistDrbg for crate::ChaCha20SeededRng {
+    fn instantiate(seed: &[u8]) -> Result<Self, Error> {
+        self::seed(seed).map(Self::from_seed)
+    }
+
+    fn generate_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.generate(dest)
+    }
+}
+
+#[cfg(feature = "shake")]
+impl DrbgParameters for crate::Shake128Rng {
+    const SEED_LEN: usize = SEED_LENGTH;
+    const KEY_LEN: usize = 0;
+    const BLOCK_LEN: usize = 168;
+    const MAX_REQUEST_LEN: usize = usize::MAX;
+}
+
+#[cfg(feature = "shake")]
+impl NistDrbg for crate::Shake128Rng {
+    fn instantiate(seed: &[u8]) -> Result<Self, Error> {
+        self::seed(seed).map(|seed| Self::new(seed.as_ref()))
+    }
+
+    fn generate_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "shake")]
+impl DrbgParameters for crate::Shake256Rng {
+    const SEED_LEN: usize = SEED_LENGTH;
+    const KEY_LEN: usize = 0;
+    const BLOCK_LEN: usize = 136;
+    const MAX_REQUEST_LEN: usize = usize::MAX;
+}
+
+#[cfg(feature = "shake")]
+impl NistDrbg for crate::Shake256Rng {
+    fn instantiate(seed: &[u8]) -> Result<Self, Error> {
+        self::seed(seed).map(Self::from_seed)
+    }
+
+    fn generate_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{NistAes128CtrRng, NistPqcAes256CtrRng};
+
+    fn first_bytes<R: NistDrbg>(seed: &[u8]) -> Result<[u8; 32], Error> {
+        let mut rng = R::instantiate(seed)?;
+        let mut buf = [0; 32];
+        rng.generate_bytes(&mut buf)?;
+        Ok(buf)
+    }
+
+    fn check<R: NistDrbg + SeedableRng<Seed = S>, S: From<[u8; N]>, const N: usize>() {
+        assert_eq!(R::SEED_LEN, N);
+        let seed: [u8; N] = core::array::from_fn(|i| i as u8);
+        let mut rng = R::from_seed(seed.into());
+        let mut buf = [0; 32];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(first_bytes::<R>(&seed), Ok(buf));
+        assert_eq!(
+            first_bytes::<R>(&seed[..N - 1]),
+            Err(Error::InvalidInputLength)
+        );
+    }
+
+    #[test]
+    fn parameters() {
+        assert_eq!(NistPqcAes256CtrRng::KEY_LEN, 32);
+        assert_eq!(NistAes128CtrRng::SEED_LEN, 32);
+        assert_eq!(NistAes128CtrRng::KEY_LEN, 16);
+        assert_eq!(NistAes128CtrRng::BLOCK_LEN, 16);
+        assert_eq!(CtrDrbg::MAX_REQUEST_LEN, 1 << 16);
+        #[cfg(feature = "hmac-drbg")]
+        {
+            assert_eq!(crate::HmacDrbgSha512::SEED_LEN, 48);
+            assert_eq!(crate::HmacDrbgSha512::KEY_LEN, 64);
+            assert_eq!(crate::HmacDrbgSha512::BLOCK_LEN, 64);
+        }
+    }
+
+    #[test]
+    fn implementations() {
+        check::<NistPqcAes256CtrRng, _, 48>();
+        check::<NistAes128CtrRng, _, 32>();
+        check::<CtrDrbg, _, 48>();
+        #[cfg(feature = "hmac-drbg")]
+        check::<crate::HmacDrbgSha256, _, 48>();
+        #[cfg(feature = "hmac-drbg")]
+        check::<crate::HmacDrbgSha512, _, 48>();
+        #[cfg(feature = "hash-drbg")]
+        check::<crate::HashDrbgSha256, _, 48>();
+        #[cfg(feature = "chacha")]
+        check::<crate::ChaCha20SeededRng, _, 48>();
+        #[cfg(feature = "shake")]
+        check::<crate::Shake256Rng, _, 48>();
+    }
+}
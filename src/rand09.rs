@@ -0,0 +1,408 @@
+//! Implementations of the traits of `rand_core` 0.9.
+//!
+//! The methods forward to the implementations of the traits of `rand_core`
+//! 0.6. Hence, the output is identical for both generations of the traits.
+
+use aes::Aes256;
+use rand_core_0_9::{
+    block::{BlockRngCore, CryptoBlockRng},
+    CryptoRng, RngCore, SeedableRng, TryCryptoRng, TryRngCore,
+};
+
+use crate::{
+    buffered::WordBufferedRng, continuous::ContinuousMode, ctr_drbg::PredictionResistantCtrDrbg,
+    dyn_drbg::DynDrbg, entropy::EntropySource, CtrDrbg, CtrDrbgCipher, Error, NistSeedExpander,
+    SeededCtrDrbg,
+};
+
+/// Implement [RngCore] and [CryptoRng] by forwarding to the traits of
+/// `rand_core` 0.6.
+macro_rules! impl_rng {
+    ([$($generics:tt)*] $ty:ty $(, where $($bounds:tt)+)?) => {
+        impl<$($generics)*> RngCore for $ty $(where $($bounds)+)? {
+            fn next_u32(&mut self) -> u32 {
+                rand_core::RngCore::next_u32(self)
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                rand_core::RngCore::next_u64(self)
+            }
+
+            fn fill_bytes(&mut self, dst: &mut [u8]) {
+                rand_core::RngCore::fill_bytes(self, dst);
+            }
+        }
+
+        impl<$($generics)*> CryptoRng for $ty $(where $($bounds)+)? {}
+    };
+}
+
+/// Implement [SeedableRng] by forwarding to the trait of `rand_core` 0.6.
+macro_rules! impl_seedable_rng {
+    ([$($generics:tt)*] $ty:ty $(, where $($bounds:tt)+)?) => {
+        impl<$($generics)*> SeedableRng for $ty
+        where
+            $($($bounds)+,)?
+            <Self as rand_core::SeedableRng>::Seed: Clone,
+        {
+            type Seed = <Self as rand_core::SeedableRng>::Seed;
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                rand_core::SeedableRng::from_seed(seed)
+            }
+
+            fn seed_from_u64(state: u64) -> Self {
+                rand_core::SeedableRng::seed_from_u64(state)
+            }
+        }
+    };
+}
+
+impl_rng!([C: CtrDrbgCipher] SeededCtrDrbg<C>);
+impl_seedable_rng!([C: CtrDrbgCipher] SeededCtrDrbg<C>);
+impl_rng!([C: CtrDrbgCipher] ContinuousMode<C>);
+impl_seedable_rng!([C: CtrDrbgCipher] ContinuousMode<C>);
+impl_rng!([C: CtrDrbgCipher] WordBufferedRng<C>);
+impl_seedable_rng!([C: CtrDrbgCipher] WordBufferedRng<C>);
+impl_rng!([] CtrDrbg);
+impl_seedable_rng!([] CtrDrbg);
+impl_rng!([E: EntropySource] PredictionResistantCtrDrbg<E>);
+impl_rng!([] NistSeedExpander);
+impl_seedable_rng!([] NistSeedExpander);
+impl_rng!(['a] dyn DynDrbg + 'a);
+
+#[cfg(feature = "chacha")]
+impl_rng!([] crate::ChaCha20SeededRng);
+#[cfg(feature = "chacha")]
+impl_seedable_rng!([] crate::ChaCha20SeededRng);
+#[cfg(feature = "hash-drbg")]
+impl_rng!([] crate::HashDrbgSha256);
+#[cfg(feature = "hash-drbg")]
+impl_seedable_rng!([] crate::HashDrbgSha256);
+#[cfg(feature = "hmac-drbg")]
+impl_rng!([D] crate::HmacDrbg<D>, where D: hmac::digest::Digest + hmac::digest::core_api::BlockSizeUser);
+#[cfg(feature = "hmac-drbg")]
+impl_seedable_rng!([D] crate::HmacDrbg<D>, where D: hmac::digest::Digest + hmac::digest::core_api::BlockSizeUser);
+#[cfg(feature = "shake")]
+impl_rng!([] crate::Shake128Rng);
+#[cfg(feature = "shake")]
+impl_seedable_rng!([] crate::Shake128Rng);
+#[cfg(feature = "shake")]
+impl_rng!([] crate::Shake256Rng);
+#[cfg(feature = "shake")]
+impl_seedable_rng!([] crate::Shake256Rng);
+
+/// Every block is the output of a single request, as for the implementation
+/// of [rand_core::block::BlockRngCore].
+impl<C: CtrDrbgCipher> BlockRngCore for SeededCtrDrbg<C> {
+    type Item = u32;
+    type Results = <Self as rand_core::block::BlockRngCore>::Results;
+
+    fn generate(&mut self, results: &mut Self::Results) {
+        rand_core::block::BlockRngCore::generate(self, results);
+    }
+}
+
+impl<C: CtrDrbgCipher> CryptoBlockRng for SeededCtrDrbg<C> {}
+
+/// Adapter implementing the traits of both `rand_core` 0.6 and 0.9
+///
+/// The DRBGs of this crate implement both generations directly. This adapter
+/// extends this to any implementation of [rand_core::RngCore], e.g., the
+/// wrappers of this crate:
+/// ```
+/// use nist_pqc_seeded_rng::{
+///     budget::BudgetRng, CompatRng, NistPqcAes256CtrRng, Seed, SeedableRng,
+/// };
+///
+/// fn consumer_0_9(rng: &mut impl rand_core_0_9::CryptoRng) {}
+///
+/// let rng = BudgetRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()), 32);
+/// consumer_0_9(&mut CompatRng::new(rng));
+/// ```
+///
+/// All requests are forwarded to the wrapped RNG, so a single instance can be
+/// used by consumers of both generations.
+#[derive(Clone, Debug, Default)]
+pub struct CompatRng<R: rand_core::RngCore> {
+    rng: R,
+}
+
+impl<R: rand_core::RngCore> CompatRng<R> {
+    /// Implement both generations of the traits for `rng`.
+    pub const fn new(rng: R) -> Self {
+        Self { rng }
+    }
+
+    /// Return a reference to the wrapped RNG.
+    pub const fn inner(&self) -> &R {
+        &self.rng
+    }
+
+    /// Return a mutable reference to the wrapped RNG.
+    pub fn inner_mut(&mut self) -> &mut R {
+        &mut self.rng
+    }
+
+    /// Return the wrapped RNG.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+}
+
+impl<R: rand_core::RngCore> From<R> for CompatRng<R> {
+    fn from(value: R) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<R: rand_core::RngCore> rand_core::RngCore for CompatRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+impl<R: rand_core::RngCore + rand_core::CryptoRng> rand_core::CryptoRng for CompatRng<R> {}
+
+impl<R: rand_core::SeedableRng + rand_core::RngCore> rand_core::SeedableRng for CompatRng<R> {
+    type Seed = R::Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(R::from_seed(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        Self::new(R::seed_from_u64(state))
+    }
+}
+
+impl<R: rand_core::RngCore> RngCore for CompatRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.rng.fill_bytes(dst);
+    }
+}
+
+impl<R: rand_core::RngCore + rand_core::CryptoRng> CryptoRng for CompatRng<R> {}
+
+impl<R> SeedableRng for CompatRng<R>
+where
+    R: rand_core::SeedableRng + rand_core::RngCore,
+    R::Seed: AsRef<[u8]> + Clone,
+{
+    type Seed = R::Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(R::from_seed(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        Self::new(R::seed_from_u64(state))
+    }
+}
+
+/// [SeededCtrDrbg] reporting rejected requests as errors
+///
+/// [SeededCtrDrbg] implements the infallible [RngCore] of `rand_core` 0.9 and
+/// panics if a request is rejected in strict mode. This adapter implements
+/// [TryRngCore] instead and reports [Error::RequestTooLarge] and
+/// [Error::ReseedRequired]:
+/// ```
+/// use nist_pqc_seeded_rng::{Error, FallibleRng, NistPqcAes256CtrRng, Seed};
+/// use rand_core_0_9::TryRngCore;
+///
+/// let mut rng = FallibleRng::new(NistPqcAes256CtrRng::from_seed_strict(Seed::default()));
+/// let mut buf = vec![0; (1 << 16) + 1];
+/// assert_eq!(rng.try_fill_bytes(&mut buf), Err(Error::RequestTooLarge));
+///
+/// // consumers of `RngCore` can use the adapter panicking on errors
+/// let mut rng = rng.unwrap_err();
+/// ```
+///
+/// The output is the output of the wrapped RNG.
+#[derive(Clone, Debug)]
+pub struct FallibleRng<C: CtrDrbgCipher = Aes256> {
+    rng: SeededCtrDrbg<C>,
+}
+
+impl<C: CtrDrbgCipher> FallibleRng<C> {
+    /// Report the rejected requests of `rng` as errors.
+    pub const fn new(rng: SeededCtrDrbg<C>) -> Self {
+        Self { rng }
+    }
+
+    /// Return the wrapped RNG.
+    pub fn into_inner(self) -> SeededCtrDrbg<C> {
+        self.rng
+    }
+}
+
+impl<C: CtrDrbgCipher> From<SeededCtrDrbg<C>> for FallibleRng<C> {
+    fn from(value: SeededCtrDrbg<C>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<C: CtrDrbgCipher> TryRngCore for FallibleRng<C> {
+    type Error = Error;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        let mut buf = [0; 4];
+        self.try_fill_bytes(&mut buf)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        let mut buf = [0; 8];
+        self.try_fill_bytes(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        self.rng.try_generate(dst, &[])
+    }
+}
+
+impl<C: CtrDrbgCipher> TryCryptoRng for FallibleRng<C> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{NistAes128CtrRng, NistPqcAes256CtrRng, Seed};
+
+    /// Draw from the RNG using the traits of both generations.
+    fn draw<R: CryptoRng + rand_core::CryptoRng + rand_core::RngCore + ?Sized>(
+        rng: &mut R,
+    ) -> (u32, u64, [u8; 10]) {
+        let mut buf = [0; 10];
+        let a = RngCore::next_u32(rng);
+        let b = rand_core::RngCore::next_u64(rng);
+        RngCore::fill_bytes(rng, &mut buf);
+        (a, b, buf)
+    }
+
+    fn draw_0_6<R: rand_core::RngCore + ?Sized>(rng: &mut R) -> (u32, u64, [u8; 10]) {
+        let mut buf = [0; 10];
+        let a = rng.next_u32();
+        let b = rng.next_u64();
+        rng.fill_bytes(&mut buf);
+        (a, b, buf)
+    }
+
+    #[test]
+    fn same_output() {
+        let mut rng_1 = <NistPqcAes256CtrRng as SeedableRng>::from_seed(Seed::default());
+        let mut rng_2 = <NistPqcAes256CtrRng as rand_core::SeedableRng>::from_seed(Seed::default());
+        assert_eq!(draw(&mut rng_1), draw_0_6(&mut rng_2));
+
+        let mut rng_1 = <CtrDrbg as SeedableRng>::from_seed(Seed::default());
+        let mut rng_2 = <CtrDrbg as rand_core::SeedableRng>::from_seed(Seed::default());
+        assert_eq!(draw(&mut rng_1), draw_0_6(&mut rng_2));
+
+        let mut rng_1 = <WordBufferedRng as SeedableRng>::from_seed(Seed::default());
+        let mut rng_2 = <WordBufferedRng as rand_core::SeedableRng>::from_seed(Seed::default());
+        assert_eq!(draw(&mut rng_1), draw_0_6(&mut rng_2));
+    }
+
+    #[test]
+    fn fallible() {
+        let mut rng = FallibleRng::new(NistPqcAes256CtrRng::from_seed_strict(Seed::default()));
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::default());
+        assert_eq!(
+            rng.try_next_u64(),
+            Ok(rand_core::RngCore::next_u64(&mut reference))
+        );
+        assert_eq!(
+            rng.try_fill_bytes(&mut [0; crate::ctr_drbg::MAX_BYTES_PER_REQUEST + 1]),
+            Err(Error::RequestTooLarge)
+        );
+
+        let mut rng = rng.unwrap_err();
+        assert_eq!(
+            RngCore::next_u32(&mut rng),
+            rand_core::RngCore::next_u32(&mut reference)
+        );
+        assert_eq!(rng.0.into_inner().reseed_counter(), 3);
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn from_os_rng() {
+        let mut rng_1 = <NistPqcAes256CtrRng as SeedableRng>::from_os_rng();
+        let mut rng_2 = <CtrDrbg as SeedableRng>::from_os_rng();
+        assert_ne!(RngCore::next_u64(&mut rng_1), RngCore::next_u64(&mut rng_2));
+    }
+
+    #[test]
+    fn block_rng() {
+        fn crypto_rng_0_6<R: rand_core::RngCore + rand_core::CryptoRng>(rng: &mut R) -> u32 {
+            rng.next_u32()
+        }
+
+        fn crypto_rng(rng: &mut impl CryptoRng) -> u32 {
+            rng.next_u32()
+        }
+
+        let mut rng_1 =
+            rand_core_0_9::block::BlockRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()));
+        let mut rng_2 =
+            rand_core::block::BlockRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()));
+        for _ in 0..20 {
+            assert_eq!(crypto_rng(&mut rng_1), crypto_rng_0_6(&mut rng_2));
+        }
+    }
+
+    #[test]
+    fn compat_rng() {
+        let rng =
+            crate::budget::BudgetRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()), 12);
+        let mut rng = CompatRng::new(rng);
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::default());
+        assert_eq!(
+            RngCore::next_u64(&mut rng),
+            rand_core::RngCore::next_u64(&mut reference)
+        );
+        assert_eq!(
+            rand_core::RngCore::next_u32(&mut rng),
+            rand_core::RngCore::next_u32(&mut reference)
+        );
+        assert_eq!(rng.inner().remaining(), 0);
+        assert!(rand_core::RngCore::try_fill_bytes(&mut rng, &mut [0; 1]).is_err());
+
+        let mut rng_1 = <CompatRng<NistPqcAes256CtrRng> as SeedableRng>::seed_from_u64(1);
+        let mut rng_2 = <NistPqcAes256CtrRng as rand_core::SeedableRng>::seed_from_u64(1);
+        assert_eq!(
+            RngCore::next_u32(&mut rng_1),
+            rand_core::RngCore::next_u32(&mut rng_2)
+        );
+    }
+
+    #[test]
+    fn seed_from_u64() {
+        let mut rng_1 = <NistAes128CtrRng as SeedableRng>::seed_from_u64(42);
+        let mut rng_2 = <NistAes128CtrRng as rand_core::SeedableRng>::seed_from_u64(42);
+        assert_eq!(
+            RngCore::next_u64(&mut rng_1),
+            rand_core::RngCore::next_u64(&mut rng_2)
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! RNG wrapper recording the output handed to the caller.
+
+use alloc::vec::Vec;
+
+use rand_core::{CryptoRng, RngCore};
+
+/// RNG recording all bytes drawn from the wrapped RNG
+///
+/// The bytes are recorded together with the boundaries of the calls, so that
+/// the random values consumed by a scheme can be inspected after a KAT
+/// failure:
+/// ```
+/// use nist_pqc_seeded_rng::{
+///     recording::RecordingRng, NistPqcAes256CtrRng, RngCore, Seed, SeedableRng,
+/// };
+///
+/// let mut rng = RecordingRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()));
+/// let mut coins = [0; 32];
+/// rng.fill_bytes(&mut coins);
+/// rng.next_u32();
+/// assert_eq!(rng.bytes().len(), 36);
+/// assert_eq!(rng.calls().next(), Some(&coins[..]));
+/// ```
+///
+/// Words returned by [RngCore::next_u32] and [RngCore::next_u64] are recorded
+/// in little-endian byte order. Failed requests are not recorded.
+#[derive(Clone, Debug)]
+pub struct RecordingRng<R: RngCore> {
+    rng: R,
+    bytes: Vec<u8>,
+    call_ends: Vec<usize>,
+}
+
+impl<R: RngCore> RecordingRng<R> {
+    /// Record the output of `rng`.
+    pub const fn new(rng: R) -> Self {
+        Self {
+            rng,
+            bytes: Vec::new(),
+            call_ends: Vec::new(),
+        }
+    }
+
+    /// All recorded bytes.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Iterate over the recorded bytes of each call.
+    pub fn calls(&self) -> impl ExactSizeIterator<Item = &[u8]> + '_ {
+        self.call_ends.iter().enumerate().map(|(index, end)| {
+            let start = index
+                .checked_sub(1)
+                .map_or(0, |index| self.call_ends[index]);
+            &self.bytes[start..*end]
+        })
+    }
+
+    /// Discard the recorded bytes.
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+        self.call_ends.clear();
+    }
+
+    /// Return the wrapped RNG.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+
+    fn record(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+        self.call_ends.push(self.bytes.len());
+    }
+}
+
+impl<R: RngCore> RngCore for RecordingRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        let value = self.rng.next_u32();
+        self.record(&value.to_le_bytes());
+        value
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let value = self.rng.next_u64();
+        self.record(&value.to_le_bytes());
+        value
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+        self.record(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.rng.try_fill_bytes(dest)?;
+        self.record(dest);
+        Ok(())
+    }
+}
+
+impl<R: RngCore + CryptoRng> CryptoRng for RecordingRng<R> {}
+
+#[cfg(test)]
+mod test {
+    use rand_core::SeedableRng;
+
+    use super::*;
+    use crate::{ctr_drbg, NistPqcAes256CtrRng, Seed};
+
+    #[test]
+    fn record() {
+        let mut rng = RecordingRng::new(NistPqcAes256CtrRng::from_seed_strict(Seed::default()));
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut buf = [0; 3];
+        rng.fill_bytes(&mut buf);
+        let word = rng.next_u64();
+        rng.fill_bytes(&mut []);
+        assert!(rng
+            .try_fill_bytes(&mut [0; ctr_drbg::MAX_BYTES_PER_REQUEST + 1])
+            .is_err());
+
+        let mut expected = [0; 3];
+        reference.fill_bytes(&mut expected);
+        assert_eq!(buf, expected);
+        assert_eq!(word, reference.next_u64());
+        assert_eq!(rng.bytes()[..3], buf);
+        assert_eq!(rng.bytes()[3..], word.to_le_bytes());
+
+        {
+            let mut calls = rng.calls();
+            assert_eq!(calls.len(), 3);
+            assert_eq!(calls.next(), Some(&buf[..]));
+            assert_eq!(calls.next(), Some(&word.to_le_bytes()[..]));
+            assert_eq!(calls.next(), Some(&[][..]));
+        }
+
+        rng.clear();
+        assert!(rng.bytes().is_empty());
+        assert_eq!(rng.calls().len(), 0);
+    }
+}
@@ -0,0 +1,109 @@
+//! RNG replaying fixed bytes.
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::Error;
+
+/// RNG returning the bytes of a buffer
+///
+/// The bytes are returned in order, e.g., to replay the coins specified by
+/// intermediate-value test vectors or recorded with a
+/// [RecordingRng](crate::recording::RecordingRng):
+/// ```
+/// use nist_pqc_seeded_rng::{replay::ReplayRng, RngCore};
+///
+/// let coins = [1, 2, 3, 4, 5];
+/// let mut rng = ReplayRng::new(&coins);
+/// let mut buf = [0; 3];
+/// rng.fill_bytes(&mut buf);
+/// assert_eq!(buf, [1, 2, 3]);
+/// assert!(rng.try_fill_bytes(&mut buf).is_err());
+/// assert_eq!(rng.remaining(), [4, 5]);
+/// ```
+///
+/// Requests exceeding the remaining bytes are rejected without consuming any
+/// bytes: [RngCore::try_fill_bytes] fails with [Error::MaxLengthExceeded] and
+/// the infallible methods panic. Words are read in little-endian byte order.
+///
+/// Warning: The output is not random. Do not use this RNG for anything else
+/// than testing.
+#[derive(Clone, Debug)]
+pub struct ReplayRng<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ReplayRng<'a> {
+    /// Replay `bytes`.
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// The bytes that have not been returned yet.
+    pub const fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Check whether all bytes have been returned.
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn take(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        if dest.len() > self.bytes.len() {
+            return Err(Error::MaxLengthExceeded);
+        }
+        let (bytes, remaining) = self.bytes.split_at(dest.len());
+        dest.copy_from_slice(bytes);
+        self.bytes = remaining;
+        Ok(())
+    }
+}
+
+impl RngCore for ReplayRng<'_> {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.take(dest).expect("replayed bytes exhausted");
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.take(dest).map_err(Into::into)
+    }
+}
+
+impl CryptoRng for ReplayRng<'_> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn replay() {
+        let bytes: [u8; 16] = core::array::from_fn(|i| i as u8);
+        let mut rng = ReplayRng::new(&bytes);
+        assert_eq!(rng.next_u32(), 0x03020100);
+        assert_eq!(rng.next_u64(), 0x0b0a090807060504);
+        let mut buf = [0; 5];
+        assert!(rng.try_fill_bytes(&mut buf).is_err());
+        assert_eq!(rng.remaining(), &bytes[12..]);
+        rng.fill_bytes(&mut buf[..4]);
+        assert_eq!(buf[..4], bytes[12..]);
+        assert!(rng.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "replayed bytes exhausted")]
+    fn exhausted() {
+        ReplayRng::new(&[0; 7]).next_u64();
+    }
+}
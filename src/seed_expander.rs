@@ -0,0 +1,197 @@
+//! Seed expander from `rng.c` based on AES-256 in counter mode.
+
+use aes::{
+    cipher::{generic_array::GenericArray, BlockEncrypt, KeyInit},
+    Aes256,
+};
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+use crate::{Error, SeedN, KEY_LENGTH, V_LENGTH};
+
+/// Length of the diversifier in bytes.
+pub const DIVERSIFIER_LENGTH: usize = 8;
+
+/// Seed expander as implemented by `seedexpander_init` and `seedexpander` in
+/// `rng.c`
+///
+/// The seed expander produces at most `maxlen - 1` bytes from a 32 byte seed
+/// and an 8 byte diversifier. It is used by submissions such as BIKE and HQC.
+///
+/// Warning: Do not use this RNG for anything else than testing.
+#[derive(Debug)]
+#[cfg_attr(feature = "zeroize", derive(zeroize::ZeroizeOnDrop))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NistSeedExpander {
+    buffer: [u8; V_LENGTH],
+    buffer_pos: usize,
+    length_remaining: u32,
+    key: [u8; KEY_LENGTH],
+    ctr: [u8; V_LENGTH],
+}
+
+impl NistSeedExpander {
+    /// Initialize the seed expander with a seed, a diversifier and the maximal
+    /// number of bytes that will be generated.
+    pub fn new(
+        seed: &[u8; KEY_LENGTH],
+        diversifier: &[u8; DIVERSIFIER_LENGTH],
+        maxlen: u32,
+    ) -> Self {
+        let mut ctr = [0; V_LENGTH];
+        ctr[..DIVERSIFIER_LENGTH].copy_from_slice(diversifier);
+        ctr[DIVERSIFIER_LENGTH..DIVERSIFIER_LENGTH + 4].copy_from_slice(&maxlen.to_be_bytes());
+        Self {
+            buffer: [0; V_LENGTH],
+            buffer_pos: V_LENGTH,
+            length_remaining: maxlen,
+            key: *seed,
+            ctr,
+        }
+    }
+
+    /// Fill `x` with the next bytes of the seed expander.
+    ///
+    /// Fails with [Error::MaxLengthExceeded] if `x` is not shorter than the
+    /// remaining output length. In that case, the state is not modified.
+    pub fn expand(&mut self, x: &mut [u8]) -> Result<(), Error> {
+        if x.len() as u64 >= self.length_remaining as u64 {
+            return Err(Error::MaxLengthExceeded);
+        }
+        self.length_remaining -= x.len() as u32;
+
+        let cipher = Aes256::new(GenericArray::from_slice(&self.key));
+        let mut offset = 0;
+        while offset < x.len() {
+            if self.buffer_pos == V_LENGTH {
+                self.buffer = self.ctr;
+                cipher.encrypt_block(GenericArray::from_mut_slice(&mut self.buffer));
+                self.buffer_pos = 0;
+                let counter = u32::from_be_bytes(self.ctr[12..].try_into().unwrap());
+                self.ctr[12..].copy_from_slice(&counter.wrapping_add(1).to_be_bytes());
+            }
+
+            let length = (x.len() - offset).min(V_LENGTH - self.buffer_pos);
+            x[offset..offset + length]
+                .copy_from_slice(&self.buffer[self.buffer_pos..self.buffer_pos + length]);
+            self.buffer_pos += length;
+            offset += length;
+        }
+        Ok(())
+    }
+}
+
+/// The seed consists of the 32 byte seed followed by the 8 byte diversifier
+/// and the maximal output length is `u32::MAX`.
+impl SeedableRng for NistSeedExpander {
+    type Seed = SeedN<{ KEY_LENGTH + DIVERSIFIER_LENGTH }>;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        let (key, diversifier) = AsRef::<[u8]>::as_ref(&seed).split_at(KEY_LENGTH);
+        Self::new(
+            key.try_into().unwrap(),
+            diversifier.try_into().unwrap(),
+            u32::MAX,
+        )
+    }
+}
+
+impl RngCore for NistSeedExpander {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.expand(dest).expect("maximal output length exceeded")
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.expand(dest).map_err(Into::into)
+    }
+}
+
+impl CryptoRng for NistSeedExpander {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn all_zeros() {
+        let mut expander =
+            NistSeedExpander::new(&[0; KEY_LENGTH], &[0; DIVERSIFIER_LENGTH], u32::MAX);
+        let mut buf = [0; 32];
+        expander.expand(&mut buf).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x2c, 0x53, 0xb6, 0x9e, 0xcd, 0x70, 0x31, 0x1a, 0x38, 0xf5, 0xe9, 0x62, 0xa6, 0xee,
+                0x4e, 0x9d, 0xb6, 0xf8, 0xa4, 0xcd, 0x4e, 0xc7, 0x37, 0xe1, 0x41, 0x57, 0x5f, 0xfd,
+                0x87, 0xd6, 0xe9, 0xe1,
+            ]
+        );
+    }
+
+    #[test]
+    fn from_seed() {
+        let mut expander = NistSeedExpander::from_seed(Default::default());
+        let mut buf = [0; 32];
+        expander.fill_bytes(&mut buf);
+        let mut expected = [0; 32];
+        NistSeedExpander::new(&[0; KEY_LENGTH], &[0; DIVERSIFIER_LENGTH], u32::MAX)
+            .fill_bytes(&mut expected);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn maxlen() {
+        let mut seed = [0; KEY_LENGTH];
+        seed.iter_mut()
+            .enumerate()
+            .for_each(|(i, byte)| *byte = i as u8);
+        let mut diversifier = [0; DIVERSIFIER_LENGTH];
+        diversifier
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, byte)| *byte = 0x20 + i as u8);
+        let mut expander = NistSeedExpander::new(&seed, &diversifier, 100);
+
+        let mut buf = [0; 7];
+        expander.expand(&mut buf).unwrap();
+        assert_eq!(buf, [0x65, 0xab, 0xbc, 0x7f, 0x15, 0xa6, 0x28]);
+        let mut buf = [0; 40];
+        expander.expand(&mut buf).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0xce, 0x7c, 0xfe, 0xc7, 0x29, 0x5d, 0x9b, 0xac, 0xbb, 0x84, 0x61, 0x52, 0x4c, 0x47,
+                0x6e, 0x42, 0xff, 0x15, 0xbf, 0x8e, 0xd4, 0xf7, 0xbd, 0xfc, 0x95, 0xac, 0x01, 0x62,
+                0x11, 0x65, 0x10, 0x32, 0xec, 0x2a, 0x52, 0xe8, 0x0d, 0x03, 0x6c, 0x3e,
+            ]
+        );
+        let mut buf = [0; 53];
+        assert_eq!(expander.expand(&mut buf), Err(Error::MaxLengthExceeded));
+        expander.expand(&mut buf[..52]).unwrap();
+        assert_eq!(
+            buf[..52],
+            [
+                0xc9, 0x61, 0x51, 0xc9, 0x9f, 0xc2, 0x04, 0x63, 0xd2, 0x99, 0x13, 0x8a, 0x95, 0xc0,
+                0x1f, 0xc0, 0x21, 0x65, 0x53, 0x6f, 0x10, 0x2b, 0x1d, 0x2c, 0x78, 0x26, 0x8e, 0x24,
+                0xad, 0x58, 0x83, 0x5f, 0xc8, 0x26, 0x18, 0x60, 0x28, 0xa7, 0x58, 0xac, 0x29, 0x7b,
+                0xcd, 0xa9, 0xa8, 0x7e, 0x28, 0x34, 0x66, 0xab, 0xa2, 0x44,
+            ]
+        );
+        assert_eq!(
+            expander.expand(&mut buf[..1]),
+            Err(Error::MaxLengthExceeded)
+        );
+        assert!(expander.try_fill_bytes(&mut buf[..1]).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+//! Serialization of byte arrays of arbitrary length.
+//!
+//! `serde` only implements its traits for arrays of up to 32 elements. The
+//! functions are compatible with the format for smaller arrays.
+
+use core::fmt;
+
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserializer, Serializer,
+};
+
+pub(crate) fn serialize<S, const N: usize>(
+    value: &[u8; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut tuple = serializer.serialize_tuple(N)?;
+    for byte in value {
+        tuple.serialize_element(byte)?;
+    }
+    tuple.end()
+}
+
+pub(crate) fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct ArrayVisitor<const N: usize>;
+
+    impl<'de, const N: usize> Visitor<'de> for ArrayVisitor<N> {
+        type Value = [u8; N];
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "an array of length {}", N)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut value = [0; N];
+            for (i, byte) in value.iter_mut().enumerate() {
+                *byte = seq
+                    .next_element()?
+                    .ok_or_else(|| Error::invalid_length(i, &self))?;
+            }
+            Ok(value)
+        }
+    }
+
+    deserializer.deserialize_tuple(N, ArrayVisitor)
+}
+
+#[cfg(test)]
+mod test {
+    use serde_test::{assert_tokens, Token};
+
+    #[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    struct Wrapper(#[serde(with = "super")] [u8; 2]);
+
+    #[test]
+    fn tuple() {
+        assert_tokens(
+            &Wrapper([1, 2]),
+            &[
+                Token::NewtypeStruct { name: "Wrapper" },
+                Token::Tuple { len: 2 },
+                Token::U8(1),
+                Token::U8(2),
+                Token::TupleEnd,
+            ],
+        );
+    }
+}
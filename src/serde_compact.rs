@@ -0,0 +1,169 @@
+//! Compact serialization of the state of [SeededCtrDrbg].
+//!
+//! The `Serialize` and `Deserialize` implementations of [SeededCtrDrbg]
+//! represent the key and V as tuples of integers. The functions of this
+//! module instead represent them as byte strings, which is considerably more
+//! compact in most formats. They are selected per field:
+//! ```
+//! use nist_pqc_seeded_rng::NistPqcAes256CtrRng;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct State {
+//!     #[serde(with = "nist_pqc_seeded_rng::serde_compact")]
+//!     rng: NistPqcAes256CtrRng,
+//! }
+//! ```
+
+use core::marker::PhantomData;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{CompatMode, CtrDrbgCipher, SeededCtrDrbg, KEY_LENGTH, V_LENGTH};
+
+#[derive(Serialize)]
+#[serde(rename = "SeededCtrDrbg")]
+struct StateRef<'a> {
+    #[serde(with = "serde_bytes")]
+    key: &'a [u8; KEY_LENGTH],
+    #[serde(with = "serde_bytes")]
+    v: &'a [u8; V_LENGTH],
+    reseed_counter: u64,
+    position: u64,
+    last_request_length: usize,
+    strict: bool,
+    compat_mode: CompatMode,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "SeededCtrDrbg")]
+struct State {
+    #[serde(with = "serde_bytes")]
+    key: [u8; KEY_LENGTH],
+    #[serde(with = "serde_bytes")]
+    v: [u8; V_LENGTH],
+    #[serde(default = "crate::initial_reseed_counter")]
+    reseed_counter: u64,
+    #[serde(default)]
+    position: u64,
+    #[serde(default)]
+    last_request_length: usize,
+    #[serde(default)]
+    strict: bool,
+    #[serde(default)]
+    compat_mode: CompatMode,
+}
+
+/// Serialize the RNG with the key and V as byte strings.
+pub fn serialize<S, C>(rng: &SeededCtrDrbg<C>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    C: CtrDrbgCipher,
+{
+    StateRef {
+        key: &rng.key,
+        v: &rng.v,
+        reseed_counter: rng.reseed_counter,
+        position: rng.position,
+        last_request_length: rng.last_request_length,
+        strict: rng.strict,
+        compat_mode: rng.compat_mode,
+    }
+    .serialize(serializer)
+}
+
+/// Deserialize a RNG serialized by [serialize].
+///
+/// As for the derived implementation, all fields except for the key and V
+/// are optional.
+pub fn deserialize<'de, D, C>(deserializer: D) -> Result<SeededCtrDrbg<C>, D::Error>
+where
+    D: Deserializer<'de>,
+    C: CtrDrbgCipher,
+{
+    let state = State::deserialize(deserializer)?;
+    Ok(SeededCtrDrbg {
+        key: state.key,
+        v: state.v,
+        reseed_counter: state.reseed_counter,
+        position: state.position,
+        last_request_length: state.last_request_length,
+        strict: state.strict,
+        compat_mode: state.compat_mode,
+        cipher: PhantomData,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use serde_test::{assert_de_tokens, assert_ser_tokens, Token};
+
+    use crate::{CompatMode, NistPqcAes256CtrRng};
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    struct Wrapper(#[serde(with = "super")] NistPqcAes256CtrRng);
+
+    impl PartialEq for Wrapper {
+        fn eq(&self, other: &Self) -> bool {
+            self.0.key == other.0.key
+                && self.0.v == other.0.v
+                && self.0.reseed_counter == other.0.reseed_counter
+                && self.0.position == other.0.position
+                && self.0.last_request_length == other.0.last_request_length
+                && self.0.strict == other.0.strict
+                && self.0.compat_mode == other.0.compat_mode
+        }
+    }
+
+    #[test]
+    fn bytes() {
+        let mut rng = NistPqcAes256CtrRng::from_state([1; 32], [2; 16]);
+        rng.compat_mode = CompatMode::AlignedExtraBlock;
+        let wrapper = Wrapper(rng);
+        let tokens = [
+            Token::NewtypeStruct { name: "Wrapper" },
+            Token::Struct {
+                name: "SeededCtrDrbg",
+                len: 7,
+            },
+            Token::Str("key"),
+            Token::Bytes(&[1; 32]),
+            Token::Str("v"),
+            Token::Bytes(&[2; 16]),
+            Token::Str("reseed_counter"),
+            Token::U64(1),
+            Token::Str("position"),
+            Token::U64(0),
+            Token::Str("last_request_length"),
+            Token::U64(0),
+            Token::Str("strict"),
+            Token::Bool(false),
+            Token::Str("compat_mode"),
+            Token::UnitVariant {
+                name: "CompatMode",
+                variant: "AlignedExtraBlock",
+            },
+            Token::StructEnd,
+        ];
+        assert_ser_tokens(&wrapper, &tokens);
+        assert_de_tokens(&wrapper, &tokens);
+    }
+
+    #[test]
+    fn key_and_v() {
+        assert_de_tokens(
+            &Wrapper(NistPqcAes256CtrRng::from_state([1; 32], [2; 16])),
+            &[
+                Token::NewtypeStruct { name: "Wrapper" },
+                Token::Struct {
+                    name: "SeededCtrDrbg",
+                    len: 2,
+                },
+                Token::Str("key"),
+                Token::Bytes(&[1; 32]),
+                Token::Str("v"),
+                Token::Bytes(&[2; 16]),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
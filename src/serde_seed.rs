@@ -0,0 +1,126 @@
+//! Serialization of the seeds.
+//!
+//! Human-readable formats represent seeds as uppercase hex strings as in the
+//! KAT files. Binary formats represent them as byte strings.
+
+use core::fmt;
+
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{hex, SeedN};
+
+struct SeedVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for SeedVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a seed of {} bytes", N)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let mut value = [0; N];
+        hex::decode(v, &mut value).map_err(E::custom)?;
+        Ok(value)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        v.try_into().map_err(|_| E::invalid_length(v.len(), &self))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut value = [0; N];
+        for (i, byte) in value.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(A::Error::invalid_length(N + 1, &self));
+        }
+        Ok(value)
+    }
+}
+
+impl<const N: usize> Serialize for SeedN<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for SeedN<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(SeedVisitor).map(Self)
+        } else {
+            deserializer.deserialize_bytes(SeedVisitor).map(Self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::de::{
+        value::{BytesDeserializer, Error as ValueError, StrDeserializer},
+        IntoDeserializer,
+    };
+    use serde_test::{assert_de_tokens_error, assert_ser_tokens, Configure, Token};
+
+    use super::*;
+    use crate::{Aes128Seed, Aes192Seed, Seed, SEED_LENGTH};
+
+    const HEX: &str = "061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7056A8C266F9EF97ED08541DBD2E1FFA1";
+    const BYTES: [u8; SEED_LENGTH] = hex::decode_array(HEX);
+
+    #[test]
+    fn human_readable() {
+        let seed = Seed::from_hex(HEX).unwrap();
+        assert_ser_tokens(&(&seed).readable(), &[Token::Str(HEX)]);
+        let deserializer: StrDeserializer<'_, ValueError> = HEX.into_deserializer();
+        assert_eq!(Seed::deserialize(deserializer).unwrap().0, seed.0);
+        assert_ser_tokens(
+            &Aes128Seed::default().readable(),
+            &[Token::Str(
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            )],
+        );
+        assert_de_tokens_error::<serde_test::Readable<Seed>>(
+            &[Token::Str(&HEX[..94])],
+            "string of invalid length: expected 96 characters, got 94",
+        );
+    }
+
+    #[test]
+    fn compact() {
+        let seed = Seed::new(BYTES);
+        assert_ser_tokens(&(&seed).compact(), &[Token::Bytes(&BYTES)]);
+        let deserializer = BytesDeserializer::<'_, ValueError>::new(&seed.0);
+        assert_eq!(Seed::deserialize(deserializer).unwrap().0, seed.0);
+        assert_de_tokens_error::<serde_test::Compact<Aes192Seed>>(
+            &[Token::Bytes(&[0; 48])],
+            "invalid length 48, expected a seed of 40 bytes",
+        );
+    }
+}
@@ -0,0 +1,291 @@
+//! RNGs expanding a seed with the SHAKE extendable-output functions.
+
+use core::fmt;
+
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128, Shake128Reader, Shake256, Shake256Reader,
+};
+
+use crate::{InvalidSeedLength, Seed};
+
+/// RNG producing the output stream of SHAKE128 on a seed
+///
+/// The seed can be of arbitrary length and the output of the RNG is
+/// `SHAKE128(seed)` read sequentially.
+pub struct Shake128Rng {
+    reader: Shake128Reader,
+}
+
+impl Shake128Rng {
+    /// Instantiate the RNG from a seed of arbitrary length.
+    pub fn new(seed: &[u8]) -> Self {
+        let mut shake = Shake128::default();
+        shake.update(seed);
+        Self {
+            reader: shake.finalize_xof(),
+        }
+    }
+}
+
+impl fmt::Debug for Shake128Rng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shake128Rng").finish_non_exhaustive()
+    }
+}
+
+/// Uses the 48 byte seed as input to SHAKE128, e.g., to instantiate the RNG
+/// via `from_entropy`.
+impl SeedableRng for Shake128Rng {
+    type Seed = Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(seed.as_ref())
+    }
+}
+
+impl From<&[u8]> for Shake128Rng {
+    fn from(value: &[u8]) -> Self {
+        Self::new(value)
+    }
+}
+
+impl RngCore for Shake128Rng {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.reader.read(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for Shake128Rng {}
+
+/// RNG producing the output stream of SHAKE256 on a 32 or 48 byte seed
+///
+/// The output of the RNG is `SHAKE256(seed)` read sequentially, which is the
+/// SHAKE256-based `randombytes` used by some reference implementations for
+/// deterministic runs outside of the known answer tests.
+pub struct Shake256Rng {
+    reader: Shake256Reader,
+}
+
+impl Shake256Rng {
+    fn new(seed: &[u8]) -> Self {
+        let mut shake = Shake256::default();
+        shake.update(seed);
+        Self {
+            reader: shake.finalize_xof(),
+        }
+    }
+}
+
+impl fmt::Debug for Shake256Rng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shake256Rng").finish_non_exhaustive()
+    }
+}
+
+impl SeedableRng for Shake256Rng {
+    type Seed = Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(seed.as_ref())
+    }
+}
+
+impl From<[u8; 32]> for Shake256Rng {
+    fn from(value: [u8; 32]) -> Self {
+        Self::new(&value)
+    }
+}
+
+impl From<[u8; 48]> for Shake256Rng {
+    fn from(value: [u8; 48]) -> Self {
+        Self::new(&value)
+    }
+}
+
+/// Accepts seeds of 32 or 48 bytes.
+///
+/// On error, the accepted length closest to the length of the slice is
+/// reported as expected length.
+impl TryFrom<&[u8]> for Shake256Rng {
+    type Error = InvalidSeedLength;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        match value.len() {
+            32 | 48 => Ok(Self::new(value)),
+            actual => Err(InvalidSeedLength {
+                expected: if actual < 40 { 32 } else { 48 },
+                actual,
+            }),
+        }
+    }
+}
+
+impl RngCore for Shake256Rng {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.reader.read(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for Shake256Rng {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shake128_empty() {
+        let mut rng = Shake128Rng::new(&[]);
+        let mut buf = [0; 64];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(
+            buf,
+            [
+                0x7f, 0x9c, 0x2b, 0xa4, 0xe8, 0x8f, 0x82, 0x7d, 0x61, 0x60, 0x45, 0x50, 0x76, 0x05,
+                0x85, 0x3e, 0xd7, 0x3b, 0x80, 0x93, 0xf6, 0xef, 0xbc, 0x88, 0xeb, 0x1a, 0x6e, 0xac,
+                0xfa, 0x66, 0xef, 0x26, 0x3c, 0xb1, 0xee, 0xa9, 0x88, 0x00, 0x4b, 0x93, 0x10, 0x3c,
+                0xfb, 0x0a, 0xee, 0xfd, 0x2a, 0x68, 0x6e, 0x01, 0xfa, 0x4a, 0x58, 0xe8, 0xa3, 0x63,
+                0x9c, 0xa8, 0xa1, 0xe3, 0xf9, 0xae, 0x57, 0xe2,
+            ]
+        );
+    }
+
+    #[test]
+    fn shake128() {
+        let mut seed = [0; 48];
+        seed.iter_mut()
+            .enumerate()
+            .for_each(|(i, byte)| *byte = i as u8);
+        let mut rng = Shake128Rng::from(seed.as_slice());
+        let mut buf = [0; 64];
+        rng.fill_bytes(&mut buf[..7]);
+        rng.fill_bytes(&mut buf[7..]);
+        assert_eq!(
+            buf,
+            [
+                0xaa, 0x0e, 0x95, 0x00, 0x5b, 0x8b, 0x23, 0x24, 0x13, 0x32, 0xed, 0xb3, 0x90, 0xa5,
+                0x3d, 0x75, 0x74, 0x7e, 0x97, 0x44, 0x4b, 0x2d, 0xb8, 0xd0, 0x4c, 0x3c, 0xba, 0xc3,
+                0xbe, 0xbe, 0x8c, 0x3b, 0x6f, 0x15, 0xfe, 0xec, 0x45, 0x94, 0x0c, 0x0c, 0x29, 0xd1,
+                0x11, 0x53, 0x17, 0xa2, 0xae, 0x57, 0x35, 0x83, 0xd9, 0xe4, 0xa5, 0x42, 0x84, 0x25,
+                0x52, 0x54, 0x91, 0x61, 0x82, 0x65, 0xac, 0x1a,
+            ]
+        );
+    }
+
+    // Expected outputs of Shake256Rng were cross-checked against
+    // hashlib.shake_256 from the Python standard library.
+    #[test]
+    fn shake256_32() {
+        let mut seed = [0; 32];
+        seed.iter_mut()
+            .enumerate()
+            .for_each(|(i, byte)| *byte = i as u8);
+        let mut rng = Shake256Rng::from(seed);
+        let mut buf = [0; 64];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(
+            buf,
+            [
+                0x69, 0xf0, 0x7c, 0x88, 0x40, 0xce, 0x80, 0x02, 0x4d, 0xb3, 0x09, 0x39, 0x88, 0x2c,
+                0x3d, 0x5b, 0xbc, 0x9c, 0x98, 0xb3, 0xe3, 0x1e, 0x45, 0x13, 0xeb, 0xd2, 0xca, 0x9b,
+                0x45, 0x03, 0xcd, 0xd3, 0xc9, 0xc9, 0x07, 0x42, 0x45, 0x2c, 0x71, 0x73, 0xd4, 0xa7,
+                0x5a, 0xc4, 0x91, 0x63, 0xe1, 0x4e, 0xe0, 0xcc, 0x24, 0xef, 0x70, 0x35, 0xb2, 0x72,
+                0xd1, 0x9a, 0x7a, 0xf1, 0x09, 0x9b, 0x33, 0x3f,
+            ]
+        );
+    }
+
+    #[test]
+    fn shake256_48() {
+        let mut seed = [0; 48];
+        seed.iter_mut()
+            .enumerate()
+            .for_each(|(i, byte)| *byte = i as u8);
+        let mut rng = Shake256Rng::from_seed(seed.into());
+        let mut buf = [0; 64];
+        rng.fill_bytes(&mut buf[..33]);
+        rng.fill_bytes(&mut buf[33..]);
+        assert_eq!(
+            buf,
+            [
+                0x0c, 0x45, 0x9b, 0xb1, 0xb4, 0xd1, 0xcd, 0x8c, 0xde, 0xc0, 0x20, 0x9f, 0x37, 0xd4,
+                0xc9, 0x15, 0x97, 0x89, 0x6c, 0xe8, 0xde, 0x09, 0x11, 0xbd, 0x02, 0x1d, 0xb4, 0x70,
+                0x29, 0xd7, 0x0d, 0xc3, 0x32, 0xad, 0x39, 0x70, 0x8e, 0x20, 0xdf, 0xe2, 0x8f, 0x32,
+                0x5b, 0x63, 0xbe, 0xff, 0xe4, 0xf8, 0x41, 0xaa, 0x83, 0x4d, 0x46, 0xf7, 0x40, 0xd3,
+                0xd9, 0x88, 0xa3, 0xbc, 0xef, 0x67, 0x8d, 0xe7,
+            ]
+        );
+
+        assert!(Shake256Rng::try_from(seed.as_slice()).is_ok());
+        assert!(Shake256Rng::try_from(&seed[..32]).is_ok());
+        assert!(Shake256Rng::try_from(&seed[..40]).is_err());
+    }
+
+    #[test]
+    fn shake256_invalid_length() {
+        let seed = [0; 64];
+        assert_eq!(
+            Shake256Rng::try_from(&seed[..16]).unwrap_err(),
+            InvalidSeedLength {
+                expected: 32,
+                actual: 16
+            }
+        );
+        assert_eq!(
+            Shake256Rng::try_from(&seed[..33]).unwrap_err(),
+            InvalidSeedLength {
+                expected: 32,
+                actual: 33
+            }
+        );
+        assert_eq!(
+            Shake256Rng::try_from(&seed[..40]).unwrap_err(),
+            InvalidSeedLength {
+                expected: 48,
+                actual: 40
+            }
+        );
+        assert_eq!(
+            Shake256Rng::try_from(seed.as_slice()).unwrap_err(),
+            InvalidSeedLength {
+                expected: 48,
+                actual: 64
+            }
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! RNGs shared between several users.
+
+use core::cell::RefCell;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::NistPqcAes256CtrRng;
+
+/// RNG shared by reference within a single thread
+///
+/// [RngCore] is implemented for `&SharedRng`, so that a single deterministic
+/// RNG can be handed to several closures or components:
+/// ```
+/// use nist_pqc_seeded_rng::{shared::SharedRng, NistPqcAes256CtrRng, RngCore, Seed, SeedableRng};
+///
+/// let rng = SharedRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()));
+/// let mut draw = || (&rng).next_u32();
+/// let first = draw();
+/// let second = (&rng).next_u32();
+/// assert_ne!(first, second);
+/// ```
+///
+/// Each request borrows the RNG mutably for the duration of the request.
+#[derive(Debug, Default)]
+pub struct SharedRng<R: RngCore = NistPqcAes256CtrRng> {
+    rng: RefCell<R>,
+}
+
+impl<R: RngCore> SharedRng<R> {
+    /// Share `rng`.
+    pub const fn new(rng: R) -> Self {
+        Self {
+            rng: RefCell::new(rng),
+        }
+    }
+
+    /// Run `f` with exclusive access to the wrapped RNG.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within `f`.
+    pub fn with<T>(&self, f: impl FnOnce(&mut R) -> T) -> T {
+        f(&mut self.rng.borrow_mut())
+    }
+
+    /// Return the wrapped RNG.
+    pub fn into_inner(self) -> R {
+        self.rng.into_inner()
+    }
+}
+
+impl<R: RngCore> From<R> for SharedRng<R> {
+    fn from(value: R) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<R: RngCore> RngCore for &SharedRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.borrow_mut().next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.borrow_mut().next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.borrow_mut().fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.rng.borrow_mut().try_fill_bytes(dest)
+    }
+}
+
+impl<R: RngCore + CryptoRng> CryptoRng for &SharedRng<R> {}
+
+/// RNG shared by reference between threads
+///
+/// [RngCore] is implemented for `&SyncRng`, so that several threads can draw
+/// from one deterministic stream:
+/// ```
+/// use nist_pqc_seeded_rng::{shared::SyncRng, NistPqcAes256CtrRng, RngCore, Seed, SeedableRng};
+///
+/// let rng = SyncRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()));
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| (&rng).next_u64());
+///     }
+/// });
+/// assert_eq!(rng.with(|rng| rng.reseed_counter()), 5);
+/// ```
+///
+/// Each request locks the RNG for the duration of the request, so requests
+/// are never interleaved. However, the order in which the threads are served
+/// depends on the scheduler. Hence, the output drawn by each thread is only
+/// deterministic if the threads synchronize their requests.
+///
+/// A poisoned lock is ignored, i.e., the RNG remains usable after a thread
+/// panicked while holding it.
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct SyncRng<R: RngCore = NistPqcAes256CtrRng> {
+    rng: Mutex<R>,
+}
+
+#[cfg(feature = "std")]
+impl<R: RngCore> SyncRng<R> {
+    /// Share `rng`.
+    pub const fn new(rng: R) -> Self {
+        Self {
+            rng: Mutex::new(rng),
+        }
+    }
+
+    /// Run `f` with exclusive access to the wrapped RNG.
+    ///
+    /// Calling this method from within `f` deadlocks or panics.
+    pub fn with<T>(&self, f: impl FnOnce(&mut R) -> T) -> T {
+        f(&mut self.lock())
+    }
+
+    /// Return the wrapped RNG.
+    pub fn into_inner(self) -> R {
+        self.rng
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, R> {
+        self.rng.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: RngCore> From<R> for SyncRng<R> {
+    fn from(value: R) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: RngCore> RngCore for &SyncRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.lock().next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.lock().next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.lock().fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.lock().try_fill_bytes(dest)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: RngCore + CryptoRng> CryptoRng for &SyncRng<R> {}
+
+#[cfg(test)]
+mod test {
+    use rand_core::SeedableRng;
+
+    use super::*;
+    use crate::Seed;
+
+    #[test]
+    fn shared() {
+        let rng = SharedRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()));
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut rng_1 = &rng;
+        let mut rng_2 = &rng;
+        assert_eq!(rng_1.next_u64(), reference.next_u64());
+        assert_eq!(rng_2.next_u32(), reference.next_u32());
+        let mut buf = [0; 10];
+        rng_1.fill_bytes(&mut buf);
+        assert_eq!(rng.with(|rng| rng.reseed_counter()), 4);
+        assert_eq!(rng.into_inner().next_u64(), {
+            reference.fill_bytes(&mut buf);
+            reference.next_u64()
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sync() {
+        let rng = SyncRng::new(NistPqcAes256CtrRng::from_seed(Seed::default()));
+        let mut reference = NistPqcAes256CtrRng::from_seed(Seed::default());
+        let mut words: std::vec::Vec<u64> = std::thread::scope(|s| {
+            let handles: std::vec::Vec<_> = (0..4).map(|_| s.spawn(|| (&rng).next_u64())).collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+        let mut expected: std::vec::Vec<u64> = (0..4).map(|_| reference.next_u64()).collect();
+        words.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(words, expected);
+        assert_eq!(rng.into_inner().reseed_counter(), 5);
+    }
+}